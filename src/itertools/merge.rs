@@ -117,8 +117,8 @@ mod test {
 
     #[test]
     fn test_merge() {
-        let iter_a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
-        let iter_b = vec![("C", 3), ("B", 2), ("A", 1), ("D", 4), ("Q", 9)];
+        let iter_a = [("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+        let iter_b = [("C", 3), ("B", 2), ("A", 1), ("D", 4), ("Q", 9)];
 
         let merge = Merge::new(iter_a.iter(), iter_b.iter(), |i| i.1, |i| i.1);
 
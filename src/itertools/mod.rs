@@ -1,11 +1,13 @@
 pub mod char_subs;
 pub mod chunked;
 pub mod merge;
+pub mod moving;
 mod take_while;
 pub mod windows;
 
 pub use take_while::take_while;
 
+use self::moving::{ConstBuf, DynBuf, MovingExtremum, MovingSum};
 use self::windows::Windows;
 use crate::itertools::chunked::Chunked;
 
@@ -21,6 +23,86 @@ pub trait IterExt<U: Clone>: Iterator<Item = U> + Sized {
         Windows::<N, _, _>::new(self)
     }
 
+    /// Returns an iterator over the sums of all windows of `size` consecutive items. Only full
+    /// windows are considered, like with `slice::windows`.
+    #[inline]
+    fn moving_sum(self, size: usize) -> MovingSum<Self, U, DynBuf<U>> {
+        MovingSum::new(self, DynBuf::new(size))
+    }
+
+    /// Same as [`IterExt::moving_sum`] but with the window size known at compile time.
+    #[inline]
+    fn moving_sum_const<const N: usize>(self) -> MovingSum<Self, U, ConstBuf<U, N>> {
+        MovingSum::new(self, ConstBuf::new())
+    }
+
+    /// Returns an iterator over the minimum of all windows of `size` consecutive items.
+    #[inline]
+    fn moving_min(self, size: usize) -> MovingExtremum<Self, U, DynBuf<(usize, U)>>
+    where
+        U: PartialOrd,
+    {
+        MovingExtremum::min(self, DynBuf::new(size))
+    }
+
+    /// Same as [`IterExt::moving_min`] but with the window size known at compile time.
+    #[inline]
+    fn moving_min_const<const N: usize>(self) -> MovingExtremum<Self, U, ConstBuf<(usize, U), N>>
+    where
+        U: PartialOrd,
+    {
+        MovingExtremum::min(self, ConstBuf::new())
+    }
+
+    /// Returns an iterator over the maximum of all windows of `size` consecutive items.
+    #[inline]
+    fn moving_max(self, size: usize) -> MovingExtremum<Self, U, DynBuf<(usize, U)>>
+    where
+        U: PartialOrd,
+    {
+        MovingExtremum::max(self, DynBuf::new(size))
+    }
+
+    /// Same as [`IterExt::moving_max`] but with the window size known at compile time.
+    #[inline]
+    fn moving_max_const<const N: usize>(self) -> MovingExtremum<Self, U, ConstBuf<(usize, U), N>>
+    where
+        U: PartialOrd,
+    {
+        MovingExtremum::max(self, ConstBuf::new())
+    }
+
+    /// Returns an iterator over the arithmetic mean of all windows of `size` consecutive items.
+    #[cfg(feature = "math")]
+    #[inline]
+    fn moving_avg(self, size: usize) -> moving::MovingAvg<Self, U, DynBuf<U>>
+    where
+        U: crate::math::num::Num,
+    {
+        moving::MovingAvg::new(self, DynBuf::new(size))
+    }
+
+    /// Same as [`IterExt::moving_avg`] but with the window size known at compile time.
+    #[cfg(feature = "math")]
+    #[inline]
+    fn moving_avg_const<const N: usize>(self) -> moving::MovingAvg<Self, U, ConstBuf<U, N>>
+    where
+        U: crate::math::num::Num,
+    {
+        moving::MovingAvg::new(self, ConstBuf::new())
+    }
+
+    /// Returns an iterator over the exponential moving average after each item. `alpha` is the
+    /// weight of the newest item and has to be within `0.0..=1.0`.
+    #[cfg(feature = "math")]
+    #[inline]
+    fn ema(self, alpha: f64) -> moving::Ema<Self>
+    where
+        U: crate::math::num::Num,
+    {
+        moving::Ema::new(self, alpha)
+    }
+
     /// Advance iterator by `n` steps, or less if the iterator returns None before n steps have
     /// been reached. Returns the actutal amount of steps the iterator made. This is different from
     /// `n` when there are less than `n` elements left in the iterator.
@@ -36,6 +118,23 @@ pub trait IterExt<U: Clone>: Iterator<Item = U> + Sized {
 
         c
     }
+
+    /// Consumes the iterator and returns descriptive statistics over all its values.
+    #[cfg(feature = "math")]
+    #[inline]
+    fn stats(self) -> crate::math::stats::Stats
+    where
+        U: crate::math::num::Num,
+    {
+        self.collect()
+    }
+
+    /// Consumes the iterator and returns `k` uniformly chosen items using reservoir sampling.
+    #[cfg(feature = "random")]
+    #[inline]
+    fn sample<R: crate::random::Rng>(self, k: usize, rng: &mut R) -> Vec<U> {
+        crate::random::reservoir_sample(self, k, rng)
+    }
 }
 
 impl<T, U> IterExt<U> for T
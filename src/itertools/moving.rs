@@ -0,0 +1,496 @@
+use super::windows::init_empty_array;
+use std::{
+    collections::VecDeque,
+    ops::{Add, Sub},
+};
+
+/// A fixed capacity double ended queue holding the items of a moving window.
+pub trait WindowBuf<T> {
+    /// Size of the window.
+    fn size(&self) -> usize;
+    fn len(&self) -> usize;
+    fn push_back(&mut self, item: T);
+    fn pop_front(&mut self) -> Option<T>;
+    fn pop_back(&mut self) -> Option<T>;
+    fn front(&self) -> Option<&T>;
+    fn back(&self) -> Option<&T>;
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Window buffer with a size chosen at runtime.
+pub struct DynBuf<T> {
+    buf: VecDeque<T>,
+    size: usize,
+}
+
+impl<T> DynBuf<T> {
+    #[inline]
+    pub(crate) fn new(size: usize) -> Self {
+        assert!(size > 0);
+        Self {
+            buf: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<T> WindowBuf<T> for DynBuf<T> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[inline]
+    fn push_back(&mut self, item: T) {
+        debug_assert!(self.buf.len() < self.size);
+        self.buf.push_back(item)
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<T> {
+        self.buf.pop_front()
+    }
+
+    #[inline]
+    fn pop_back(&mut self) -> Option<T> {
+        self.buf.pop_back()
+    }
+
+    #[inline]
+    fn front(&self) -> Option<&T> {
+        self.buf.front()
+    }
+
+    #[inline]
+    fn back(&self) -> Option<&T> {
+        self.buf.back()
+    }
+}
+
+/// Window buffer with a size known at compile time, stored inline as ring buffer.
+pub struct ConstBuf<T, const N: usize> {
+    buf: [Option<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> ConstBuf<T, N> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        assert!(N > 0);
+        Self {
+            buf: init_empty_array(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn idx(&self, offset: usize) -> usize {
+        (self.head + offset) % N
+    }
+}
+
+impl<T, const N: usize> WindowBuf<T> for ConstBuf<T, N> {
+    #[inline]
+    fn size(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn push_back(&mut self, item: T) {
+        assert!(self.len < N);
+        let idx = self.idx(self.len);
+        self.buf[idx] = Some(item);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.buf[self.head].take();
+        self.head = self.idx(1);
+        self.len -= 1;
+        item
+    }
+
+    #[inline]
+    fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let idx = self.idx(self.len);
+        self.buf[idx].take()
+    }
+
+    #[inline]
+    fn front(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.buf[self.head].as_ref()
+    }
+
+    #[inline]
+    fn back(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.buf[self.idx(self.len - 1)].as_ref()
+    }
+}
+
+/// An iterator yielding the sum of each full window of consecutive items. The sum is updated
+/// incrementally, so each item costs O(1) amortized regardless of the window size.
+///
+/// To keep the rounding error of floats from building up, the sum is recomputed from the window
+/// once per window size items. It's also recomputed when an infinite or `NaN` item leaves the
+/// window, so such an item only affects the windows containing it.
+pub struct MovingSum<I, T, B> {
+    iter: I,
+    buf: B,
+    sum: Option<T>,
+    /// Incremental updates since the sum was last recomputed.
+    updates: usize,
+}
+
+impl<I, T, B> MovingSum<I, T, B> {
+    #[inline]
+    pub(crate) fn new(iter: I, buf: B) -> Self {
+        Self {
+            iter,
+            buf,
+            sum: None,
+            updates: 0,
+        }
+    }
+}
+
+impl<I, T, B> MovingSum<I, T, B>
+where
+    T: Copy + Add<Output = T>,
+    B: WindowBuf<T>,
+{
+    /// Sums the window by rotating the buffer once.
+    fn recompute(&mut self) -> Option<T> {
+        let mut sum = None;
+        for _ in 0..self.buf.len() {
+            let item = self.buf.pop_front()?;
+            sum = Some(sum.map_or(item, |s| s + item));
+            self.buf.push_back(item);
+        }
+        sum
+    }
+}
+
+impl<I, T, B> Iterator for MovingSum<I, T, B>
+where
+    I: Iterator<Item = T>,
+    T: Copy + PartialEq + Add<Output = T> + Sub<Output = T>,
+    B: WindowBuf<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = self.iter.next()?;
+
+            let mut stale = false;
+            if self.buf.len() == self.buf.size() {
+                let old = self.buf.pop_front().unwrap();
+                self.sum = self.sum.map(|s| s - old);
+                stale = !is_finite(old);
+            }
+            self.buf.push_back(next);
+            self.updates += 1;
+
+            if stale || self.updates >= self.buf.size() {
+                self.sum = self.recompute();
+                self.updates = 0;
+            } else {
+                self.sum = Some(self.sum.map_or(next, |s| s + next));
+            }
+
+            if self.buf.len() == self.buf.size() {
+                return self.sum;
+            }
+        }
+    }
+}
+
+/// Returns `false` for infinite and `NaN` floats, which can't be subtracted from a sum again.
+/// `x - x` is only `NaN` for those and zero for all other numbers.
+#[allow(clippy::eq_op)]
+#[inline]
+fn is_finite<T: Copy + PartialEq + Sub<Output = T>>(x: T) -> bool {
+    let zero = x - x;
+    zero == zero
+}
+
+/// An iterator yielding the arithmetic mean of each full window of consecutive items. It's based
+/// on [`MovingSum`], so rounding errors and infinite or `NaN` items are handled the same way.
+#[cfg(feature = "math")]
+pub struct MovingAvg<I, T, B> {
+    sum: MovingSum<I, T, B>,
+}
+
+#[cfg(feature = "math")]
+impl<I, T, B> MovingAvg<I, T, B> {
+    #[inline]
+    pub(crate) fn new(iter: I, buf: B) -> Self {
+        Self {
+            sum: MovingSum::new(iter, buf),
+        }
+    }
+}
+
+#[cfg(feature = "math")]
+impl<I, T, B> Iterator for MovingAvg<I, T, B>
+where
+    I: Iterator<Item = T>,
+    T: crate::math::num::Num,
+    B: WindowBuf<T>,
+{
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let sum = self.sum.next()?;
+        Some(sum.to_f64() / self.sum.buf.size() as f64)
+    }
+}
+
+/// An iterator yielding the exponential moving average after each item, weighting the newest item
+/// with `alpha` and the previous average with `1 - alpha`. The first average is the first item.
+#[cfg(feature = "math")]
+pub struct Ema<I> {
+    iter: I,
+    alpha: f64,
+    avg: Option<f64>,
+}
+
+#[cfg(feature = "math")]
+impl<I> Ema<I> {
+    #[inline]
+    pub(crate) fn new(iter: I, alpha: f64) -> Self {
+        assert!((0.0..=1.0).contains(&alpha));
+        Self {
+            iter,
+            alpha,
+            avg: None,
+        }
+    }
+}
+
+#[cfg(feature = "math")]
+impl<I> Iterator for Ema<I>
+where
+    I: Iterator,
+    I::Item: crate::math::num::Num,
+{
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = crate::math::num::Num::to_f64(self.iter.next()?);
+        let avg = match self.avg {
+            Some(avg) => self.alpha * next + (1.0 - self.alpha) * avg,
+            None => next,
+        };
+        self.avg = Some(avg);
+        Some(avg)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator yielding the minimum or maximum of each full window of consecutive items. Uses a
+/// monotonic deque, so each item costs O(1) amortized regardless of the window size.
+pub struct MovingExtremum<I, T, B> {
+    iter: I,
+    /// Candidates with their position. Values are monotonic from front to back.
+    buf: B,
+    pos: usize,
+    /// Returns `true` if the first value should be kept over the second one.
+    keep: fn(&T, &T) -> bool,
+}
+
+impl<I, T, B> MovingExtremum<I, T, B>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    pub(crate) fn min(iter: I, buf: B) -> Self {
+        Self::new(iter, buf, |a, b| a < b)
+    }
+
+    #[inline]
+    pub(crate) fn max(iter: I, buf: B) -> Self {
+        Self::new(iter, buf, |a, b| a > b)
+    }
+
+    #[inline]
+    fn new(iter: I, buf: B, keep: fn(&T, &T) -> bool) -> Self {
+        Self {
+            iter,
+            buf,
+            pos: 0,
+            keep,
+        }
+    }
+}
+
+impl<I, T, B> Iterator for MovingExtremum<I, T, B>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+    B: WindowBuf<(usize, T)>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next = self.iter.next()?;
+            let pos = self.pos;
+            self.pos += 1;
+
+            let size = self.buf.size();
+            while self.buf.front().is_some_and(|f| f.0 + size <= pos) {
+                self.buf.pop_front();
+            }
+            while self.buf.back().is_some_and(|b| !(self.keep)(&b.1, &next)) {
+                self.buf.pop_back();
+            }
+            self.buf.push_back((pos, next));
+
+            if pos + 1 >= size {
+                return self.buf.front().map(|i| i.1.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::itertools::IterExt;
+    use test_case::test_case;
+
+    fn naive<F: Fn(&[i32]) -> i32>(data: &[i32], size: usize, f: F) -> Vec<i32> {
+        data.windows(size).map(f).collect()
+    }
+
+    fn data() -> Vec<i32> {
+        (0..200).map(|i| (i * 7919 % 101) - 50).collect()
+    }
+
+    #[test]
+    fn moving_sum() {
+        let data = data();
+        for size in 1..12 {
+            let exp = naive(&data, size, |w| w.iter().sum());
+            let res = data.iter().copied().moving_sum(size).collect::<Vec<_>>();
+            assert_eq!(res, exp);
+        }
+
+        let res = data
+            .iter()
+            .copied()
+            .moving_sum_const::<5>()
+            .collect::<Vec<_>>();
+        assert_eq!(res, naive(&data, 5, |w| w.iter().sum()));
+    }
+
+    #[test]
+    fn moving_min_max() {
+        let data = data();
+        for size in 1..12 {
+            let exp = naive(&data, size, |w| *w.iter().min().unwrap());
+            let res = data.iter().copied().moving_min(size).collect::<Vec<_>>();
+            assert_eq!(res, exp);
+
+            let exp = naive(&data, size, |w| *w.iter().max().unwrap());
+            let res = data.iter().copied().moving_max(size).collect::<Vec<_>>();
+            assert_eq!(res, exp);
+        }
+
+        let res = data
+            .iter()
+            .copied()
+            .moving_min_const::<4>()
+            .collect::<Vec<_>>();
+        assert_eq!(res, naive(&data, 4, |w| *w.iter().min().unwrap()));
+        let res = data
+            .iter()
+            .copied()
+            .moving_max_const::<7>()
+            .collect::<Vec<_>>();
+        assert_eq!(res, naive(&data, 7, |w| *w.iter().max().unwrap()));
+    }
+
+    #[test]
+    fn shorter_than_window() {
+        assert_eq!((0..3).moving_sum(4).next(), None);
+        assert_eq!((0..3).moving_max_const::<4>().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "math")]
+    fn moving_avg() {
+        let res = (1..=5).moving_avg(2).collect::<Vec<_>>();
+        assert_eq!(res, vec![1.5, 2.5, 3.5, 4.5]);
+        let res = (1..=5).moving_avg_const::<5>().collect::<Vec<_>>();
+        assert_eq!(res, vec![3.0]);
+    }
+
+    #[test]
+    fn float_sum_recovers() {
+        // Adding 1.0 to 1e16 is lost to rounding, so the incremental sum would stay off by one.
+        let data = std::iter::once(1e16).chain(std::iter::repeat_n(1.0, 20));
+        let res = data.moving_sum(4).collect::<Vec<_>>();
+        assert!(res[4..].iter().all(|sum| *sum == 4.0));
+    }
+
+    #[test_case(f64::NAN; "nan")]
+    #[test_case(f64::INFINITY; "infinity")]
+    fn non_finite_leaves_window(x: f64) {
+        let data = [1.0, 2.0, x, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let res = data.iter().copied().moving_sum(2).collect::<Vec<_>>();
+        assert_eq!(res[0], 3.0);
+        assert!(!res[1].is_finite() && !res[2].is_finite());
+        assert_eq!(&res[3..], &[7.0, 9.0, 11.0, 13.0]);
+
+        let res = data
+            .iter()
+            .copied()
+            .moving_sum_const::<3>()
+            .collect::<Vec<_>>();
+        assert_eq!(&res[3..], &[12.0, 15.0, 18.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "math")]
+    fn ema() {
+        let res = [2, 4, 8].iter().copied().ema(0.5).collect::<Vec<_>>();
+        assert_eq!(res, vec![2.0, 3.0, 5.5]);
+    }
+}
@@ -60,6 +60,19 @@ where
     }
 }
 
+trait EmptyArrayInit: Sized {
+    const NONE: Option<Self>;
+}
+
+impl<T> EmptyArrayInit for T {
+    const NONE: Option<T> = None;
+}
+
+#[inline]
+pub fn init_empty_array<T, const N: usize>() -> [Option<T>; N] {
+    [EmptyArrayInit::NONE; N]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::itertools::IterExt;
@@ -100,16 +113,3 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 }
-
-trait EmptyArrayInit: Sized {
-    const NONE: Option<Self>;
-}
-
-impl<T> EmptyArrayInit for T {
-    const NONE: Option<T> = None;
-}
-
-#[inline]
-pub fn init_empty_array<T, const N: usize>() -> [Option<T>; N] {
-    [EmptyArrayInit::NONE; N]
-}
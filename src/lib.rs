@@ -2,6 +2,8 @@
 pub mod itertools;
 #[cfg(feature = "math")]
 pub mod math;
+#[cfg(feature = "random")]
+pub mod random;
 #[cfg(feature = "stringtools")]
 pub mod stringtools;
 #[cfg(feature = "vectools")]
@@ -0,0 +1,328 @@
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{self, Display},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A signed decimal fixed-point number with `SCALE` fractional digits, stored as `i64` scaled by
+/// `10^SCALE`. Addition and subtraction are exact; multiplication and division round half away
+/// from zero to the last fractional digit.
+///
+/// The operators panic on overflow, use the `checked_*` methods to handle it.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::fixed::Fixed;
+/// type Money = Fixed<2>;
+/// let price: Money = "19.99".parse().unwrap();
+/// let total = price * Money::from_int(3) + "0.03".parse().unwrap();
+/// assert_eq!(total.to_string(), "60.00");
+/// assert_eq!((total / Money::from_int(7)).to_string(), "8.57");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<const SCALE: u32>(i64);
+
+impl<const SCALE: u32> Fixed<SCALE> {
+    /// `10^SCALE`, the raw value of one.
+    const FACTOR: i64 = 10i64.pow(SCALE);
+
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(Self::FACTOR);
+    pub const MIN: Self = Self(i64::MIN);
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Creates a value from its raw representation, which is the value times `10^SCALE`.
+    #[inline]
+    pub const fn from_raw(raw: i64) -> Self {
+        Self(raw)
+    }
+
+    #[inline]
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a value from an integer. Panics if it doesn't fit.
+    #[inline]
+    pub fn from_int(value: i64) -> Self {
+        Self::checked_from_int(value).expect("integer out of range")
+    }
+
+    #[inline]
+    pub fn checked_from_int(value: i64) -> Option<Self> {
+        value.checked_mul(Self::FACTOR).map(Self)
+    }
+
+    /// Returns the integer part, rounding towards zero.
+    #[inline]
+    pub fn trunc(self) -> i64 {
+        self.0 / Self::FACTOR
+    }
+
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::FACTOR as f64
+    }
+
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let raw = div_round(self.0 as i128 * rhs.0 as i128, Self::FACTOR as i128);
+        i64::try_from(raw).ok().map(Self)
+    }
+
+    /// Returns `None` on overflow or if `rhs` is zero.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        let raw = div_round(self.0 as i128 * Self::FACTOR as i128, rhs.0 as i128);
+        i64::try_from(raw).ok().map(Self)
+    }
+
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Returns the absolute value. Panics for [`Fixed::MIN`], whose absolute value doesn't fit, use
+    /// [`Fixed::checked_abs`] to handle this.
+    #[inline]
+    pub fn abs(self) -> Self {
+        self.checked_abs().expect("absolute value overflowed")
+    }
+
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+}
+
+/// Divides `n` by `d`, rounding half away from zero.
+#[inline]
+fn div_round(n: i128, d: i128) -> i128 {
+    let (q, r) = (n / d, n % d);
+    if 2 * r.abs() >= d.abs() {
+        q + n.signum() * d.signum()
+    } else {
+        q
+    }
+}
+
+impl<const SCALE: u32> Display for Fixed<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        let factor = Self::FACTOR as u64;
+
+        if SCALE == 0 {
+            return write!(f, "{}{}", sign, abs);
+        }
+
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / factor,
+            abs % factor,
+            width = SCALE as usize
+        )
+    }
+}
+
+/// Error returned when parsing a [`Fixed`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFixedError {
+    /// The string is empty or contains only a sign.
+    Empty,
+    /// The string contains something other than a sign, digits and a single decimal point.
+    InvalidDigit,
+    /// The string has more fractional digits than `SCALE`.
+    TooPrecise,
+    /// The value doesn't fit.
+    Overflow,
+}
+
+impl Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFixedError::Empty => {
+                write!(f, "cannot parse fixed-point number from empty string")
+            }
+            ParseFixedError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseFixedError::TooPrecise => write!(f, "too many fractional digits"),
+            ParseFixedError::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+impl Error for ParseFixedError {}
+
+impl<const SCALE: u32> FromStr for Fixed<SCALE> {
+    type Err = ParseFixedError;
+
+    /// Parses decimal numbers like `-12.345`, `0.5`, `.5` or `7`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseFixedError::Empty);
+        }
+        if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ParseFixedError::InvalidDigit);
+        }
+        if frac.len() > SCALE as usize {
+            return Err(ParseFixedError::TooPrecise);
+        }
+
+        let digits = |s: &str| {
+            s.bytes().try_fold(0i128, |acc, b| {
+                acc.checked_mul(10)?.checked_add((b - b'0') as i128)
+            })
+        };
+
+        let int = digits(int).ok_or(ParseFixedError::Overflow)?;
+        let frac =
+            digits(frac).ok_or(ParseFixedError::Overflow)? * 10i128.pow(SCALE - frac.len() as u32);
+        let mut raw = int
+            .checked_mul(Self::FACTOR as i128)
+            .and_then(|i| i.checked_add(frac))
+            .ok_or(ParseFixedError::Overflow)?;
+        if negative {
+            raw = -raw;
+        }
+
+        i64::try_from(raw)
+            .map(Self)
+            .map_err(|_| ParseFixedError::Overflow)
+    }
+}
+
+macro_rules! impl_op {
+    ($($tr:ident $f:ident $checked:ident $msg:literal, $tr_assign:ident $f_assign:ident),*) => {$(
+        impl<const SCALE: u32> $tr for Fixed<SCALE> {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<const SCALE: u32> $tr_assign for Fixed<SCALE> {
+            #[inline]
+            fn $f_assign(&mut self, rhs: Self) {
+                *self = self.$f(rhs);
+            }
+        }
+    )*};
+}
+
+impl_op!(
+    Add add checked_add "attempt to add with overflow", AddAssign add_assign,
+    Sub sub checked_sub "attempt to subtract with overflow", SubAssign sub_assign,
+    Mul mul checked_mul "attempt to multiply with overflow", MulAssign mul_assign,
+    Div div checked_div "attempt to divide by zero or with overflow", DivAssign div_assign
+);
+
+impl<const SCALE: u32> Neg for Fixed<SCALE> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl<const SCALE: u32> Sum for Fixed<SCALE> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    type F2 = Fixed<2>;
+
+    fn f2(s: &str) -> F2 {
+        s.parse().unwrap()
+    }
+
+    #[test_case("12.34", Ok(1234); "decimal")]
+    #[test_case("-0.5", Ok(-50); "negative below one")]
+    #[test_case(".5", Ok(50); "no integer part")]
+    #[test_case("7", Ok(700); "integer")]
+    #[test_case("+7.", Ok(700); "trailing point")]
+    #[test_case("-", Err(ParseFixedError::Empty); "sign only")]
+    #[test_case("1.234", Err(ParseFixedError::TooPrecise); "too precise")]
+    #[test_case("1,5", Err(ParseFixedError::InvalidDigit); "comma")]
+    #[test_case("1.-5", Err(ParseFixedError::InvalidDigit); "inner sign")]
+    #[test_case("92233720368547758.08", Err(ParseFixedError::Overflow); "overflow")]
+    #[test_case("-92233720368547758.08", Ok(i64::MIN); "min")]
+    fn parse(s: &str, exp: Result<i64, ParseFixedError>) {
+        assert_eq!(s.parse::<F2>().map(F2::raw), exp);
+    }
+
+    #[test_case(1234, "12.34"; "positive")]
+    #[test_case(-5, "-0.05"; "negative")]
+    #[test_case(0, "0.00"; "zero")]
+    #[test_case(i64::MIN, "-92233720368547758.08"; "min")]
+    fn display(raw: i64, exp: &str) {
+        assert_eq!(F2::from_raw(raw).to_string(), exp);
+        assert_eq!(exp.parse::<F2>().unwrap().raw(), raw);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(f2("0.10") + f2("0.20"), f2("0.30"));
+        assert_eq!(f2("1.00") - f2("2.50"), f2("-1.50"));
+        assert_eq!(f2("1.50") * f2("1.50"), f2("2.25"));
+        assert_eq!(f2("0.05") * f2("0.50"), f2("0.03"));
+        assert_eq!(f2("-0.05") * f2("0.50"), f2("-0.03"));
+        assert_eq!(f2("10.00") / f2("3.00"), f2("3.33"));
+        assert_eq!(f2("2.00") / f2("3.00"), f2("0.67"));
+        assert_eq!(-f2("1.00"), f2("-1.00"));
+        assert_eq!(vec![f2("0.10"); 10].into_iter().sum::<F2>(), F2::ONE);
+        assert!(f2("-0.01") < F2::ZERO);
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(F2::MAX.checked_add(F2::from_raw(1)), None);
+        assert_eq!(F2::MAX.checked_mul(f2("2")), None);
+        assert_eq!(F2::ONE.checked_div(F2::ZERO), None);
+        assert_eq!(F2::MIN.checked_neg(), None);
+        assert_eq!(F2::MIN.checked_abs(), None);
+        assert_eq!(f2("-1.50").checked_abs(), Some(f2("1.50")));
+        assert_eq!(F2::checked_from_int(i64::MAX), None);
+        assert_eq!(Fixed::<0>::from_int(5).to_string(), "5");
+    }
+}
@@ -0,0 +1,159 @@
+use super::num::Float;
+
+/// Linearly interpolates between `a` and `b`. `t = 0` returns `a`, `t = 1` returns `b`; values
+/// outside of `0..=1` extrapolate.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::lerp;
+/// assert_eq!(lerp(10.0, 20.0, 0.25), 12.5);
+/// ```
+#[inline]
+pub fn lerp<T: Float>(a: T, b: T, t: T) -> T {
+    // Exact at both ends, unlike `a + (b - a) * t`.
+    a * (T::one() - t) + b * t
+}
+
+/// Inverse of [`lerp`]: returns `t` so that `lerp(a, b, t) == value`. Returns NaN if `a == b`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::inverse_lerp;
+/// assert_eq!(inverse_lerp(10.0, 20.0, 12.5), 0.25);
+/// ```
+#[inline]
+pub fn inverse_lerp<T: Float>(a: T, b: T, value: T) -> T {
+    (value - a) / (b - a)
+}
+
+/// Maps `value` from the range `in_min..in_max` to the range `out_min..out_max`. Doesn't clamp.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::remap;
+/// assert_eq!(remap(5.0, 0.0, 10.0, 100.0, 200.0), 150.0);
+/// ```
+#[inline]
+pub fn remap<T: Float>(value: T, in_min: T, in_max: T, out_min: T, out_max: T) -> T {
+    lerp(out_min, out_max, inverse_lerp(in_min, in_max, value))
+}
+
+/// Restricts `value` to `min..=max`. Unlike `Ord::clamp` this only requires `PartialOrd`, so it
+/// works for floats and integers alike. NaN is returned unchanged.
+#[inline]
+pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    assert!(min <= max);
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Hermite interpolation between 0 and 1 when `x` moves from `edge0` to `edge1`. Returns 0 for
+/// `x <= edge0` and 1 for `x >= edge1`. If both edges are equal this is a step from 0 to 1 at
+/// `edge0`, so `x == edge0` returns 1.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::smoothstep;
+/// assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+/// ```
+#[inline]
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    if edge0 == edge1 {
+        return if x < edge0 { T::zero() } else { T::one() };
+    }
+    let t = clamp(inverse_lerp(edge0, edge1, x), T::zero(), T::one());
+    t * t * (T::from_f64(3.0) - T::from_f64(2.0) * t)
+}
+
+/// Returns `true` if `a` and `b` are equal within a few ULPs relative to their magnitude, or
+/// within `EPSILON` for values around zero.
+#[inline]
+pub fn approx_eq<T: Float>(a: T, b: T) -> bool {
+    approx_eq_eps(a, b, T::EPSILON, T::EPSILON * T::from_f64(4.0))
+}
+
+/// Returns `true` if `|a - b|` is at most `abs_eps` or at most `rel_eps` times the larger
+/// magnitude of `a` and `b`. The absolute epsilon handles values close to zero where relative
+/// comparisons break down.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::approx_eq_eps;
+/// assert!(approx_eq_eps(1000.0, 1000.1, 0.0, 1e-3));
+/// assert!(!approx_eq_eps(1.0, 1.1, 0.01, 1e-3));
+/// assert!(approx_eq_eps(1e-12, 0.0, 1e-9, 0.0));
+/// ```
+pub fn approx_eq_eps<T: Float>(a: T, b: T, abs_eps: T, rel_eps: T) -> bool {
+    if a == b {
+        // Also handles infinities of the same sign.
+        return true;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return false;
+    }
+
+    let diff = (a - b).abs();
+    diff <= abs_eps || diff <= rel_eps * a.abs().max(b.abs())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn lerp_ends() {
+        assert_eq!(lerp(0.1f64, 0.7, 1.0), 0.7);
+        assert_eq!(lerp(0.1f32, 0.7, 0.0), 0.1);
+        assert_eq!(lerp(0.0, 10.0, 1.5), 15.0);
+        assert!(inverse_lerp(1.0f64, 1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn remap_reverse() {
+        assert_eq!(remap(2.0, 0.0, 10.0, 1.0, 0.0), 0.8);
+        assert_eq!(remap(-1.0f32, -1.0, 1.0, 0.0, 255.0), 0.0);
+    }
+
+    #[test_case(-1.0, 0.0; "below")]
+    #[test_case(0.25, 0.15625; "inside")]
+    #[test_case(1.0, 1.0; "at edge")]
+    fn test_smoothstep(x: f64, exp: f64) {
+        assert!(approx_eq(smoothstep(0.0, 1.0, x), exp));
+    }
+
+    #[test_case(-1.0, 0.0; "below")]
+    #[test_case(0.5, 1.0; "at edge")]
+    #[test_case(2.0, 1.0; "above")]
+    fn smoothstep_equal_edges(x: f64, exp: f64) {
+        assert_eq!(smoothstep(0.5, 0.5, x), exp);
+        assert_eq!(smoothstep(0.5f32, 0.5, x as f32), exp as f32);
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp(5, 0, 3), 3);
+        assert_eq!(clamp(-0.5, 0.0, 1.0), 0.0);
+        assert!(clamp(f64::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(approx_eq(0.1 + 0.2, 0.3));
+        assert!(!approx_eq(0.1f32, 0.2));
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY));
+        assert!(!approx_eq(f64::INFINITY, f64::NEG_INFINITY));
+        assert!(!approx_eq(f64::NAN, f64::NAN));
+    }
+}
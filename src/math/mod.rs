@@ -1,6 +1,24 @@
+pub mod fixed;
+pub mod interp;
+pub mod modular;
+pub mod num;
+pub mod pairs;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod primes;
+pub mod rational;
+pub mod stats;
+
+pub use interp::{approx_eq, approx_eq_eps, clamp, inverse_lerp, lerp, remap, smoothstep};
+pub use modular::{
+    add_mod, checked_gcd, checked_lcm, ext_gcd, gcd, inv_mod, lcm, mul_mod, pow_mod, sub_mod, Mod,
+};
+
+use num::AbsDiff;
 use std::ops::Sub;
 
-/// Calculates the difference between `a` and `b`. This method never fails.
+/// Calculates the difference between `a` and `b`. This method never fails for unsigned integers.
+/// For signed integers the difference might not fit into `T`, use [`abs_diff`] instead.
 #[inline]
 pub fn diff<T: Sub<Output = T> + PartialOrd>(a: T, b: T) -> T {
     if a > b {
@@ -10,6 +28,22 @@ pub fn diff<T: Sub<Output = T> + PartialOrd>(a: T, b: T) -> T {
     }
 }
 
+/// Calculates the absolute difference between `a` and `b` without overflowing. Signed integers
+/// return their unsigned counterpart.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::abs_diff;
+/// assert_eq!(abs_diff(i32::MIN, i32::MAX), u32::MAX);
+/// assert_eq!(abs_diff(3u8, 10), 7);
+/// assert_eq!(abs_diff(1.5, -1.0), 2.5);
+/// ```
+#[inline]
+pub fn abs_diff<T: AbsDiff>(a: T, b: T) -> T::Output {
+    a.abs_diff(b)
+}
+
 /// Returns an iterator over all combinations of items in `list`. The permutations are not
 /// symmetrical.
 /// This means if (a,b) ∈ `list` => (b,a) ∉ `list`.
@@ -18,7 +52,7 @@ pub fn diff<T: Sub<Output = T> + PartialOrd>(a: T, b: T) -> T {
 /// Example:
 ///
 /// ```rust
-/// use permut_combo::*;
+/// use utilsrs::math::*;
 /// let list = vec!['a', 'b', 'c'];
 /// let res = twos_perm_non_sym(&list, false).map(|i| (*i.0,*i.1)).collect::<Vec<_>>();
 /// assert_eq!(res, vec![('a','b'),('a','c'),('b','c')]);
@@ -58,7 +92,7 @@ pub fn twos_perm_non_sym<T>(list: &[T], reflexive: bool) -> impl Iterator<Item =
 /// Example:
 ///
 /// ```rust
-/// use permut_combo::*;
+/// use utilsrs::math::*;
 /// let list = vec!['a', 'b', 'c'];
 /// let res = twos_perm_sym(&list, false).map(|i| (*i.0,*i.1)).collect::<Vec<_>>();
 /// assert_eq!(res, vec![('a', 'b'), ('a', 'c'), ('b', 'a'), ('b', 'c'), ('c', 'a'), ('c',
@@ -94,8 +128,3 @@ pub fn twos_perm_sym<T>(list: &[T], reflexive: bool) -> impl Iterator<Item = (&T
         Some(res)
     })
 }
-
-#[inline]
-pub fn pow_mod(base: usize, exponent: u32, modulo: usize) -> usize {
-    (base % modulo).pow(exponent) % modulo
-}
@@ -0,0 +1,367 @@
+use super::num::{Integer, Signed};
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Calculates `base^exponent mod modulo` using square-and-multiply. Intermediate products never
+/// overflow. For signed types `modulo` has to be positive and the result is in `0..modulo`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::pow_mod;
+/// assert_eq!(pow_mod(2, 10, 1000), 24);
+/// assert_eq!(pow_mod(3, 200, 1_000_000_007), 136_318_165);
+/// assert_eq!(pow_mod(-2i8, 3, 10), 2);
+/// ```
+#[inline]
+pub fn pow_mod<T: Integer>(base: T, exponent: u32, modulo: T) -> T {
+    pow_mod_wide(base, exponent as u128, modulo)
+}
+
+/// Same as [`pow_mod`] but accepting exponents of any width.
+pub(crate) fn pow_mod_wide<T: Integer>(base: T, mut exponent: u128, modulo: T) -> T {
+    let mut res = T::one().rem_euclid(modulo);
+    let mut base = base.rem_euclid(modulo);
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            res = res.mul_mod(base, modulo);
+        }
+        base = base.mul_mod(base, modulo);
+        exponent >>= 1;
+    }
+
+    res
+}
+
+/// Calculates `a * b mod modulo` without overflowing.
+#[inline]
+pub fn mul_mod<T: Integer>(a: T, b: T, modulo: T) -> T {
+    a.mul_mod(b, modulo)
+}
+
+/// Calculates `a + b mod modulo` without overflowing.
+#[inline]
+pub fn add_mod<T: Integer>(a: T, b: T, modulo: T) -> T {
+    let (a, b) = (a.rem_euclid(modulo), b.rem_euclid(modulo));
+    if a >= modulo - b {
+        a - (modulo - b)
+    } else {
+        a + b
+    }
+}
+
+/// Calculates `a - b mod modulo` without underflowing.
+#[inline]
+pub fn sub_mod<T: Integer>(a: T, b: T, modulo: T) -> T {
+    let (a, b) = (a.rem_euclid(modulo), b.rem_euclid(modulo));
+    if a >= b {
+        a - b
+    } else {
+        modulo - (b - a)
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`. `gcd(0, 0)` is 0 and the result is never
+/// negative. Panics if the result isn't representable, which only happens for signed types if
+/// both arguments are `MIN` or zero, like `gcd(MIN, 0)`. Use [`checked_gcd`] to handle this.
+#[inline]
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("gcd overflowed")
+}
+
+/// Same as [`gcd`] but returns `None` instead of panicking if the result isn't representable.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::checked_gcd;
+/// assert_eq!(checked_gcd(-12i8, 18), Some(6));
+/// assert_eq!(checked_gcd(i8::MIN, 4), Some(4));
+/// assert_eq!(checked_gcd(i8::MIN, i8::MIN), None);
+/// ```
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while !b.is_zero() {
+        // `MIN % -1` overflows although the remainder is zero.
+        (a, b) = (b, a.checked_rem(b).unwrap_or_else(T::zero));
+    }
+    if a < T::zero() {
+        T::zero().checked_sub(a)
+    } else {
+        Some(a)
+    }
+}
+
+/// Returns the least common multiple of `a` and `b`, or 0 if one of them is 0. The result is
+/// never negative. Panics if the result isn't representable, like `lcm(i32::MAX, i32::MAX - 1)`.
+/// Use [`checked_lcm`] to handle this.
+#[inline]
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm overflowed")
+}
+
+/// Same as [`lcm`] but returns `None` instead of panicking if the result isn't representable.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::checked_lcm;
+/// assert_eq!(checked_lcm(-4i8, 6), Some(12));
+/// assert_eq!(checked_lcm(i32::MAX, i32::MAX - 1), None);
+/// assert_eq!(checked_lcm(i8::MIN, 1), None);
+/// ```
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+    // The gcd is positive, so the division can't overflow.
+    let res = (a / checked_gcd(a, b)?).checked_mul(b)?;
+    if res < T::zero() {
+        T::zero().checked_sub(res)
+    } else {
+        Some(res)
+    }
+}
+
+/// Extended euclidean algorithm. Returns `(g, x, y)` with `g = gcd(a, b)` and `a*x + b*y = g`.
+pub fn ext_gcd<T: Integer + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns the modular multiplicative inverse of `a`, so that `a * inv_mod(a) mod modulo = 1`.
+/// Returns `None` if `a` and `modulo` aren't coprime.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::inv_mod;
+/// assert_eq!(inv_mod(3, 11), Some(4));
+/// assert_eq!(inv_mod(2, 4), None);
+/// ```
+pub fn inv_mod<T: Integer>(a: T, modulo: T) -> Option<T> {
+    // Extended euclid keeping the coefficient of `a` reduced modulo `modulo` so it works for
+    // unsigned types and never overflows.
+    let (mut old_r, mut r) = (modulo, a.rem_euclid(modulo));
+    let (mut old_t, mut t) = (T::zero(), T::one().rem_euclid(modulo));
+
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_t, t) = (t, sub_mod(old_t, q.mul_mod(t, modulo), modulo));
+    }
+
+    (old_r == T::one()).then_some(old_t)
+}
+
+/// An integer in the ring of integers modulo `M`. All arithmetic operations are done modulo `M`
+/// without overflowing.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::Mod;
+/// type M7 = Mod<7>;
+/// assert_eq!(M7::new(5) + M7::new(4), M7::new(2));
+/// assert_eq!(M7::new(3) * M7::new(5), M7::new(1));
+/// assert_eq!(M7::new(1) / M7::new(3), M7::new(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Mod<const M: usize>(usize);
+
+impl<const M: usize> Mod<M> {
+    /// Creates a new value, reducing `value` modulo `M`.
+    #[inline]
+    pub fn new(value: usize) -> Self {
+        Self(value % M)
+    }
+
+    /// Returns the reduced value in `0..M`.
+    #[inline]
+    pub fn value(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub fn pow(self, exponent: u32) -> Self {
+        Self(pow_mod(self.0, exponent, M))
+    }
+
+    /// Returns the multiplicative inverse or `None` if the value isn't coprime to `M`.
+    #[inline]
+    pub fn inv(self) -> Option<Self> {
+        inv_mod(self.0, M).map(Self)
+    }
+}
+
+impl<const M: usize> From<usize> for Mod<M> {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: usize> Display for Mod<M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const M: usize> Add for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(add_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: usize> Sub for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(sub_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: usize> Mul for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(mul_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: usize> Div for Mod<M> {
+    type Output = Self;
+
+    /// Multiplies with the inverse of `rhs`. Panics if `rhs` isn't invertible modulo `M`.
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv().expect("divisor is not invertible")
+    }
+}
+
+impl<const M: usize> Neg for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(sub_mod(0, self.0, M))
+    }
+}
+
+macro_rules! impl_assign {
+    ($($tr:ident $f:ident $op:tt),*) => {
+        $(impl<const M: usize> $tr for Mod<M> {
+            #[inline]
+            fn $f(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        })*
+    };
+}
+
+impl_assign!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2, 10, 1000, 24; "small")]
+    #[test_case(3, 200, 1_000_000_007, 136_318_165; "overflowing pow")]
+    #[test_case(usize::MAX, u32::MAX, usize::MAX - 1, 1; "max")]
+    #[test_case(5, 0, 1, 0; "modulo one")]
+    #[test_case(0, 0, 7, 1; "zero pow zero")]
+    fn test_pow_mod(base: usize, exp: u32, modulo: usize, exp_res: usize) {
+        assert_eq!(pow_mod(base, exp, modulo), exp_res);
+    }
+
+    #[test]
+    fn test_pow_mod_widths() {
+        assert_eq!(pow_mod(7u8, 200, 251), 20);
+        assert_eq!(pow_mod(-3i64, 3, 5), 3);
+        assert_eq!(
+            pow_mod(3u128, 1000, u128::MAX),
+            67_783_823_664_505_539_789_923_714_872_827_080_886
+        );
+        assert_eq!(inv_mod(3u8, 250), Some(167));
+        assert_eq!(inv_mod(-3i32, 7), Some(2));
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(lcm(-4i16, 6), 12);
+    }
+
+    #[test]
+    fn test_add_sub_mod() {
+        let m = usize::MAX - 2;
+        assert_eq!(add_mod(m - 1, m - 1, m), m - 2);
+        assert_eq!(sub_mod(1, 2, m), m - 1);
+        assert_eq!(add_mod(3, 4, 5), 2);
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(i8::MIN, 6), 2);
+        assert_eq!(gcd(i8::MIN, -1), 1);
+        assert_eq!(checked_gcd(i8::MIN, 0), None);
+        assert_eq!(checked_gcd(0, i8::MIN), None);
+        assert_eq!(checked_gcd(i8::MIN, i8::MIN), None);
+        assert_eq!(checked_gcd(u8::MAX, 0), Some(u8::MAX));
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(i8::MIN, 0), 0);
+        assert_eq!(lcm(u32::MAX, 1), u32::MAX);
+        assert_eq!(checked_lcm(i32::MAX, i32::MAX - 1), None);
+        assert_eq!(checked_lcm(i8::MIN, 2), None);
+        assert_eq!(checked_lcm(i8::MIN, i8::MIN), None);
+        assert_eq!(checked_lcm(-64i8, 2), Some(64));
+
+        let (g, x, y) = ext_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_inv_mod() {
+        for a in 1..13 {
+            assert_eq!(mul_mod(a, inv_mod(a, 13).unwrap(), 13), 1);
+        }
+        assert_eq!(inv_mod(6, 9), None);
+    }
+
+    #[test]
+    fn test_mod_type() {
+        type M = Mod<1_000_000_007>;
+        let mut a = M::new(1_000_000_000);
+        a += M::new(10);
+        assert_eq!(a.value(), 3);
+        assert_eq!((-M::new(1)).value(), 1_000_000_006);
+        assert_eq!(M::new(2).pow(30).value(), 73_741_817);
+        assert_eq!(M::new(10) / M::new(5), M::new(2));
+        assert_eq!(M::new(0).inv(), None);
+    }
+}
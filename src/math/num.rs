@@ -0,0 +1,476 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// Types having an additive identity.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+
+    fn is_zero(&self) -> bool;
+}
+
+/// Types having a multiplicative identity.
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+/// Base trait for all primitive numbers, integers as well as floats.
+pub trait Num:
+    Copy
+    + PartialOrd
+    + Debug
+    + Display
+    + Default
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// Converts the value to `f64`, with the same semantics as an `as` cast.
+    fn to_f64(self) -> f64;
+
+    /// Converts a `f64` to `Self`, with the same semantics as an `as` cast.
+    fn from_f64(v: f64) -> Self;
+}
+
+/// Arithmetic returning `None` on overflow or division by zero.
+pub trait CheckedOps: Sized {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+/// Arithmetic wrapping around at the boundaries of the type.
+pub trait WrappingOps: Sized {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+/// Arithmetic saturating at the boundaries of the type.
+pub trait SaturatingOps: Sized {
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+/// Primitive integers of all widths, signed and unsigned.
+pub trait Integer: Num + Ord + Eq + Hash + CheckedOps + WrappingOps + SaturatingOps {
+    const MIN: Self;
+    const MAX: Self;
+    const BITS: u32;
+
+    /// Calculates the quotient of euclidean division, matching [`Integer::rem_euclid`].
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the least non negative remainder of `self / rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates `self * rhs mod modulo` without overflowing. The result is always in
+    /// `0..modulo`.
+    fn mul_mod(self, rhs: Self, modulo: Self) -> Self;
+
+    #[inline]
+    fn is_even(self) -> bool {
+        (self % (Self::one() + Self::one())).is_zero()
+    }
+}
+
+/// Signed numbers, integers as well as floats.
+pub trait Signed: Num + Neg<Output = Self> {
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+
+    #[inline]
+    fn is_negative(self) -> bool {
+        self < Self::zero()
+    }
+}
+
+/// Unsigned integers.
+pub trait Unsigned: Integer {}
+
+/// Primitive floating point numbers.
+pub trait Float: Signed {
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+/// Absolute difference of two values which never overflows. For signed integers the result is the
+/// unsigned type of the same width, since the difference may not fit into the signed type.
+pub trait AbsDiff: Sized {
+    type Output;
+
+    fn abs_diff(self, other: Self) -> Self::Output;
+}
+
+macro_rules! impl_num {
+    ($zero:expr, $one:expr; $($t:ty)*) => {$(
+        impl Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                $zero
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == $zero
+            }
+        }
+
+        impl One for $t {
+            #[inline]
+            fn one() -> Self {
+                $one
+            }
+        }
+
+        impl Num for $t {
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline]
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_ops {
+    ($($t:ty)*) => {$(
+        impl CheckedOps for $t {
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            #[inline]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            #[inline]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+        }
+
+        impl WrappingOps for $t {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+        }
+
+        impl SaturatingOps for $t {
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+/// Implements `Integer` for types which have a primitive of at least twice their width to
+/// calculate products in.
+macro_rules! impl_integer {
+    ($($t:ty => $wide:ty),*) => {$(
+        impl Integer for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn div_euclid(self, rhs: Self) -> Self {
+                <$t>::div_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn mul_mod(self, rhs: Self, modulo: Self) -> Self {
+                (self as $wide * rhs as $wide).rem_euclid(modulo as $wide) as $t
+            }
+        }
+    )*};
+}
+
+/// Implements `Integer` for 128 bit types, which don't have a wider primitive. Products are
+/// calculated using double-and-add.
+macro_rules! impl_integer_128 {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn div_euclid(self, rhs: Self) -> Self {
+                <$t>::div_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn mul_mod(self, rhs: Self, modulo: Self) -> Self {
+                let mut a = self.rem_euclid(modulo);
+                let mut b = rhs.rem_euclid(modulo);
+                let mut res = 0;
+
+                while b > 0 {
+                    if b & 1 == 1 {
+                        res = super::add_mod(res, a, modulo);
+                    }
+                    a = super::add_mod(a, a, modulo);
+                    b >>= 1;
+                }
+
+                res
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty)*) => {$(
+        impl Signed for $t {
+            #[inline]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ty)*) => {$(
+        impl Float for $t {
+            const EPSILON: Self = <$t>::EPSILON;
+            const INFINITY: Self = <$t>::INFINITY;
+            const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+            const NAN: Self = <$t>::NAN;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            #[inline]
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                <$t>::powi(self, n)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_num!(0, 1; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_num!(0.0, 1.0; f32 f64);
+impl_int_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_integer!(
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128, usize => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128, isize => i128
+);
+impl_integer_128!(u128, i128);
+impl_signed!(i8 i16 i32 i64 i128 isize f32 f64);
+impl_float!(f32 f64);
+
+macro_rules! impl_abs_diff {
+    ($($t:ty => $out:ty),*) => {$(
+        impl AbsDiff for $t {
+            type Output = $out;
+
+            #[inline]
+            fn abs_diff(self, other: Self) -> Self::Output {
+                <$t>::abs_diff(self, other)
+            }
+        }
+    )*};
+}
+
+impl_abs_diff!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+impl AbsDiff for f32 {
+    type Output = f32;
+
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self::Output {
+        (self - other).abs()
+    }
+}
+
+impl AbsDiff for f64 {
+    type Output = f64;
+
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self::Output {
+        (self - other).abs()
+    }
+}
+
+impl Unsigned for u8 {}
+impl Unsigned for u16 {}
+impl Unsigned for u32 {}
+impl Unsigned for u64 {}
+impl Unsigned for u128 {}
+impl Unsigned for usize {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sum<T: Num>(items: &[T]) -> T {
+        items.iter().fold(T::zero(), |a, b| a + *b)
+    }
+
+    #[test]
+    fn generic_sum() {
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+        assert_eq!(sum(&[-1i64, 2, 3]), 4);
+        assert_eq!(sum(&[0.5f32, 0.25]), 0.75);
+    }
+
+    #[test]
+    fn mul_mod_all_widths() {
+        assert_eq!(200u8.mul_mod(200, 251), (200 * 200 % 251) as u8);
+        assert_eq!(u64::MAX.mul_mod(u64::MAX, 1_000_000_007), 114_944_269);
+        assert_eq!(u128::MAX.mul_mod(u128::MAX, u128::MAX - 1), 1);
+        assert_eq!((-3i32).mul_mod(5, 7), 6);
+        assert_eq!(i128::MIN.mul_mod(1, 7), i128::MIN.rem_euclid(7));
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(CheckedOps::checked_add(250u8, 10), None);
+        assert_eq!(CheckedOps::checked_div(1i32, 0), None);
+        assert_eq!(SaturatingOps::saturating_sub(1u8, 10), 0);
+        assert_eq!(WrappingOps::wrapping_add(i8::MAX, 1), i8::MIN);
+    }
+}
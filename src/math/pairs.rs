@@ -0,0 +1,324 @@
+use std::ops::Range;
+
+/// Returns the amount of pairs [`twos_perm_non_sym`](super::twos_perm_non_sym) yields for a list
+/// of `len` items.
+#[inline]
+pub fn pair_count_non_sym(len: usize, reflexive: bool) -> usize {
+    triangular(rows_non_sym(len, reflexive))
+}
+
+/// Returns the amount of pairs [`twos_perm_sym`](super::twos_perm_sym) yields for a list of `len`
+/// items.
+#[inline]
+pub fn pair_count_sym(len: usize, reflexive: bool) -> usize {
+    if reflexive {
+        len * len
+    } else {
+        len * len.saturating_sub(1)
+    }
+}
+
+/// Returns the position of the pair `(i, j)` within the output of
+/// [`twos_perm_non_sym`](super::twos_perm_non_sym) for a list of `len` items. Returns `None` if
+/// the pair is never yielded.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::pairs::*;
+/// assert_eq!(rank_non_sym(3, 0, 1, false), Some(0));
+/// assert_eq!(rank_non_sym(3, 1, 2, false), Some(2));
+/// assert_eq!(rank_non_sym(3, 1, 1, false), None);
+/// ```
+pub fn rank_non_sym(len: usize, i: usize, j: usize, reflexive: bool) -> Option<usize> {
+    let first = if reflexive { i } else { i + 1 };
+    if j >= len || j < first {
+        return None;
+    }
+
+    Some(row_offset_non_sym(len, i, reflexive) + (j - first))
+}
+
+/// Returns the `k`-th pair of indices yielded by [`twos_perm_non_sym`](super::twos_perm_non_sym)
+/// for a list of `len` items without iterating over the previous pairs. Returns `None` if there
+/// are less than `k + 1` pairs.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::pairs::*;
+/// assert_eq!(unrank_non_sym(3, 0, false), Some((0, 1)));
+/// assert_eq!(unrank_non_sym(3, 2, false), Some((1, 2)));
+/// assert_eq!(unrank_non_sym(3, 3, false), None);
+/// ```
+pub fn unrank_non_sym(len: usize, k: usize, reflexive: bool) -> Option<(usize, usize)> {
+    let rows = rows_non_sym(len, reflexive);
+    let total = triangular(rows);
+    if k >= total {
+        return None;
+    }
+
+    // Row `i` is followed by `rows - i - 1` rows which together hold `triangular(rows - i - 1)`
+    // pairs. Find the smallest amount of remaining rows that still covers `total - k` pairs.
+    let needed = total - k;
+    let (mut lo, mut hi) = (1, rows);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if triangular(mid) >= needed {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    let i = rows - lo;
+    let first = if reflexive { i } else { i + 1 };
+    Some((i, first + (k - row_offset_non_sym(len, i, reflexive))))
+}
+
+/// Returns the position of the pair `(i, j)` within the output of
+/// [`twos_perm_sym`](super::twos_perm_sym) for a list of `len` items. Returns `None` if the pair
+/// is never yielded.
+pub fn rank_sym(len: usize, i: usize, j: usize, reflexive: bool) -> Option<usize> {
+    if i >= len || j >= len || (!reflexive && i == j) {
+        return None;
+    }
+
+    if reflexive {
+        Some(i * len + j)
+    } else {
+        Some(i * (len - 1) + if j < i { j } else { j - 1 })
+    }
+}
+
+/// Returns the `k`-th pair of indices yielded by [`twos_perm_sym`](super::twos_perm_sym) for a list
+/// of `len` items without iterating over the previous pairs. Returns `None` if there are less than
+/// `k + 1` pairs.
+pub fn unrank_sym(len: usize, k: usize, reflexive: bool) -> Option<(usize, usize)> {
+    if k >= pair_count_sym(len, reflexive) {
+        return None;
+    }
+
+    if reflexive {
+        return Some((k / len, k % len));
+    }
+
+    let (i, j) = (k / (len - 1), k % (len - 1));
+    Some((i, if j < i { j } else { j + 1 }))
+}
+
+/// Splits `0..total` into `parts` contiguous ranges whose lengths differ by at most one. Empty
+/// ranges are returned if `parts` is bigger than `total`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::pairs::*;
+/// let ranges = split_even(10, 3).collect::<Vec<_>>();
+/// assert_eq!(ranges, vec![0..4, 4..7, 7..10]);
+/// ```
+pub fn split_even(total: usize, parts: usize) -> impl Iterator<Item = Range<usize>> {
+    assert!(parts > 0);
+
+    let size = total / parts;
+    let rest = total % parts;
+    let mut start = 0;
+
+    (0..parts).map(move |part| {
+        let end = start + size + usize::from(part < rest);
+        let range = start..end;
+        start = end;
+        range
+    })
+}
+
+/// Returns an iterator over the pairs of [`twos_perm_non_sym`](super::twos_perm_non_sym) whose
+/// position lies within `range`. Seeking to the start of `range` doesn't iterate over the skipped
+/// pairs.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::pairs::*;
+/// let list = vec!['a', 'b', 'c'];
+/// let res = twos_perm_non_sym_range(&list, false, 1..3).map(|i| (*i.0, *i.1)).collect::<Vec<_>>();
+/// assert_eq!(res, vec![('a', 'c'), ('b', 'c')]);
+/// ```
+pub fn twos_perm_non_sym_range<T>(
+    list: &[T],
+    reflexive: bool,
+    range: Range<usize>,
+) -> impl Iterator<Item = (&T, &T)> {
+    let len = list.len();
+    let mut pos = unrank_non_sym(len, range.start, reflexive);
+    let count = range
+        .end
+        .min(pair_count_non_sym(len, reflexive))
+        .saturating_sub(range.start);
+
+    std::iter::from_fn(move || {
+        let (i, j) = pos?;
+        pos = Some(next_non_sym(len, (i, j), reflexive));
+        Some((&list[i], &list[j]))
+    })
+    .take(count)
+}
+
+/// Returns an iterator over the pairs of [`twos_perm_sym`](super::twos_perm_sym) whose position
+/// lies within `range`. Seeking to the start of `range` doesn't iterate over the skipped pairs.
+pub fn twos_perm_sym_range<T>(
+    list: &[T],
+    reflexive: bool,
+    range: Range<usize>,
+) -> impl Iterator<Item = (&T, &T)> {
+    let len = list.len();
+    let mut pos = unrank_sym(len, range.start, reflexive);
+    let count = range
+        .end
+        .min(pair_count_sym(len, reflexive))
+        .saturating_sub(range.start);
+
+    std::iter::from_fn(move || {
+        let (i, j) = pos?;
+        pos = Some(next_sym(len, (i, j), reflexive));
+        Some((&list[i], &list[j]))
+    })
+    .take(count)
+}
+
+/// Returns the pair following `(i, j)` in the order of `twos_perm_non_sym`. The result is out of
+/// bounds if `(i, j)` is the last pair.
+#[inline]
+pub(crate) fn next_non_sym(len: usize, (i, j): (usize, usize), reflexive: bool) -> (usize, usize) {
+    if j + 1 < len {
+        (i, j + 1)
+    } else {
+        let i = i + 1;
+        (i, if reflexive { i } else { i + 1 })
+    }
+}
+
+/// Returns the pair following `(i, j)` in the order of `twos_perm_sym`. The result is out of
+/// bounds if `(i, j)` is the last pair.
+#[inline]
+pub(crate) fn next_sym(len: usize, (i, j): (usize, usize), reflexive: bool) -> (usize, usize) {
+    let mut next = (i, j + 1);
+    if !reflexive && next.1 == i {
+        next.1 += 1;
+    }
+    if next.1 >= len {
+        next = (i + 1, 0);
+    }
+    next
+}
+
+/// Amount of rows (distinct first indices) `twos_perm_non_sym` iterates over.
+#[inline]
+fn rows_non_sym(len: usize, reflexive: bool) -> usize {
+    if reflexive {
+        len
+    } else {
+        len.saturating_sub(1)
+    }
+}
+
+/// Position of the first pair with `i` as first index.
+#[inline]
+fn row_offset_non_sym(len: usize, i: usize, reflexive: bool) -> usize {
+    let rows = rows_non_sym(len, reflexive);
+    triangular(rows) - triangular(rows - i)
+}
+
+/// Returns `n * (n + 1) / 2` without overflowing in the intermediate product.
+#[inline]
+fn triangular(n: usize) -> usize {
+    if n.is_multiple_of(2) {
+        (n / 2) * (n + 1)
+    } else {
+        n * n.div_ceil(2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::{twos_perm_non_sym, twos_perm_sym};
+    use test_case::test_case;
+
+    fn indices(len: usize) -> Vec<usize> {
+        (0..len).collect()
+    }
+
+    #[test_case(true; "reflexive")]
+    #[test_case(false; "non reflexive")]
+    fn rank_unrank_non_sym(reflexive: bool) {
+        for len in 0..10 {
+            let list = indices(len);
+            let pairs = twos_perm_non_sym(&list, reflexive).collect::<Vec<_>>();
+            assert_eq!(pairs.len(), pair_count_non_sym(len, reflexive));
+
+            for (k, (i, j)) in pairs.iter().enumerate() {
+                assert_eq!(unrank_non_sym(len, k, reflexive), Some((**i, **j)));
+                assert_eq!(rank_non_sym(len, **i, **j, reflexive), Some(k));
+            }
+            assert_eq!(unrank_non_sym(len, pairs.len(), reflexive), None);
+        }
+    }
+
+    #[test_case(true; "reflexive")]
+    #[test_case(false; "non reflexive")]
+    fn rank_unrank_sym(reflexive: bool) {
+        // `twos_perm_sym` can't handle single item lists when not being reflexive.
+        for len in 2..10 {
+            let list = indices(len);
+            let pairs = twos_perm_sym(&list, reflexive).collect::<Vec<_>>();
+            assert_eq!(pairs.len(), pair_count_sym(len, reflexive));
+
+            for (k, (i, j)) in pairs.iter().enumerate() {
+                assert_eq!(unrank_sym(len, k, reflexive), Some((**i, **j)));
+                assert_eq!(rank_sym(len, **i, **j, reflexive), Some(k));
+            }
+            assert_eq!(unrank_sym(len, pairs.len(), reflexive), None);
+        }
+    }
+
+    #[test_case(10, 3; "uneven")]
+    #[test_case(9, 3; "even")]
+    #[test_case(2, 5; "more parts than items")]
+    #[test_case(0, 1; "empty")]
+    fn split_even_covers_all(total: usize, parts: usize) {
+        let ranges = split_even(total, parts).collect::<Vec<_>>();
+        assert_eq!(ranges.len(), parts);
+        assert_eq!(ranges.first().unwrap().start, 0);
+        assert_eq!(ranges.last().unwrap().end, total);
+        for w in ranges.windows(2) {
+            assert_eq!(w[0].end, w[1].start);
+            assert!(w[0].len() >= w[1].len() && w[0].len() - w[1].len() <= 1);
+        }
+    }
+
+    #[test_case(true; "reflexive")]
+    #[test_case(false; "non reflexive")]
+    fn range_iter(reflexive: bool) {
+        let list = indices(7);
+        let all = twos_perm_non_sym(&list, reflexive).collect::<Vec<_>>();
+        let all_sym = twos_perm_sym(&list, reflexive).collect::<Vec<_>>();
+
+        for parts in 1..6 {
+            let joined = split_even(all.len(), parts)
+                .flat_map(|r| twos_perm_non_sym_range(&list, reflexive, r))
+                .collect::<Vec<_>>();
+            assert_eq!(joined, all);
+
+            let joined = split_even(all_sym.len(), parts)
+                .flat_map(|r| twos_perm_sym_range(&list, reflexive, r))
+                .collect::<Vec<_>>();
+            assert_eq!(joined, all_sym);
+        }
+
+        assert_eq!(
+            twos_perm_non_sym_range(&list, reflexive, 100..200).count(),
+            0
+        );
+    }
+}
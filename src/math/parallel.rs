@@ -0,0 +1,278 @@
+use super::pairs::{
+    next_non_sym, next_sym, pair_count_non_sym, pair_count_sym, split_even, unrank_non_sym,
+    unrank_sym,
+};
+use std::{num::NonZeroUsize, ops::Range, thread};
+
+/// A splittable producer over the pairs yielded by [`twos_perm_non_sym`](super::twos_perm_non_sym)
+/// or [`twos_perm_sym`](super::twos_perm_sym). Splitting is done by position without iterating
+/// over any pair, so each part can be handed to a separate thread.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::parallel::PairProducer;
+/// let list = (0..100).collect::<Vec<u64>>();
+/// let sum = PairProducer::non_sym(&list, false).par_fold(4, || 0, |acc, (a, b)| acc + a * b, |a, b| a + b);
+/// let seq: u64 = utilsrs::math::twos_perm_non_sym(&list, false).map(|(a, b)| a * b).sum();
+/// assert_eq!(sum, seq);
+/// ```
+#[derive(Debug)]
+pub struct PairProducer<'a, T> {
+    list: &'a [T],
+    sym: bool,
+    reflexive: bool,
+    range: Range<usize>,
+}
+
+impl<'a, T> Clone for PairProducer<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            sym: self.sym,
+            reflexive: self.reflexive,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a, T> PairProducer<'a, T> {
+    /// Creates a new producer over all pairs of [`twos_perm_non_sym`](super::twos_perm_non_sym).
+    #[inline]
+    pub fn non_sym(list: &'a [T], reflexive: bool) -> Self {
+        let range = 0..pair_count_non_sym(list.len(), reflexive);
+        Self {
+            list,
+            sym: false,
+            reflexive,
+            range,
+        }
+    }
+
+    /// Creates a new producer over all pairs of [`twos_perm_sym`](super::twos_perm_sym).
+    #[inline]
+    pub fn sym(list: &'a [T], reflexive: bool) -> Self {
+        let range = 0..pair_count_sym(list.len(), reflexive);
+        Self {
+            list,
+            sym: true,
+            reflexive,
+            range,
+        }
+    }
+
+    /// Returns the amount of pairs this producer yields.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Splits the producer into two at `index`. The first one yields the first `index` pairs, the
+    /// second one all remaining pairs.
+    pub fn split_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.len());
+
+        let mid = self.range.start + index;
+        let left = Self {
+            range: self.range.start..mid,
+            ..self.clone()
+        };
+        let right = Self {
+            range: mid..self.range.end,
+            ..self
+        };
+        (left, right)
+    }
+
+    /// Splits the producer into `parts` contiguous producers whose lengths differ by at most one.
+    pub fn split(self, parts: usize) -> impl Iterator<Item = Self> + 'a {
+        let start = self.range.start;
+        split_even(self.len(), parts).map(move |r| Self {
+            range: start + r.start..start + r.end,
+            ..self.clone()
+        })
+    }
+}
+
+impl<'a, T: Sync> PairProducer<'a, T> {
+    /// Calls `f` for each pair, spreading the work evenly across `threads` scoped threads.
+    pub fn par_for_each<F>(self, threads: usize, f: F)
+    where
+        F: Fn((&'a T, &'a T)) + Sync,
+    {
+        self.par_fold(threads, || (), |_, pair| f(pair), |_, _| ());
+    }
+
+    /// Folds all pairs using `threads` scoped threads. Each thread starts with a value created by
+    /// `identity` and folds its share of pairs into it using `fold`. The results of all threads
+    /// are then combined in order using `reduce`.
+    pub fn par_fold<R, I, F, Red>(self, threads: usize, identity: I, fold: F, reduce: Red) -> R
+    where
+        R: Send,
+        I: Fn() -> R + Sync,
+        F: Fn(R, (&'a T, &'a T)) -> R + Sync,
+        Red: Fn(R, R) -> R,
+    {
+        let threads = threads.max(1).min(self.len().max(1));
+        if threads == 1 {
+            return self.into_iter().fold(identity(), &fold);
+        }
+
+        let (identity, fold) = (&identity, &fold);
+        thread::scope(|s| {
+            let handles = self
+                .split(threads)
+                .map(|part| s.spawn(move || part.into_iter().fold(identity(), fold)))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .reduce(reduce)
+                .unwrap()
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for PairProducer<'a, T> {
+    type Item = (&'a T, &'a T);
+    type IntoIter = PairIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.list.len();
+        let pos = if self.sym {
+            unrank_sym(len, self.range.start, self.reflexive)
+        } else {
+            unrank_non_sym(len, self.range.start, self.reflexive)
+        };
+
+        PairIter {
+            list: self.list,
+            sym: self.sym,
+            reflexive: self.reflexive,
+            pos: pos.unwrap_or_default(),
+            left: self.range.len(),
+        }
+    }
+}
+
+/// Iterator over the pairs of a [`PairProducer`].
+pub struct PairIter<'a, T> {
+    list: &'a [T],
+    sym: bool,
+    reflexive: bool,
+    pos: (usize, usize),
+    left: usize,
+}
+
+impl<'a, T> Iterator for PairIter<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+
+        let (i, j) = self.pos;
+        let len = self.list.len();
+        self.pos = if self.sym {
+            next_sym(len, self.pos, self.reflexive)
+        } else {
+            next_non_sym(len, self.pos, self.reflexive)
+        };
+
+        Some((&self.list[i], &self.list[j]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for PairIter<'a, T> {}
+
+/// Returns the amount of threads the current machine can run in parallel, falling back to 1 if it
+/// can't be determined.
+#[inline]
+pub fn available_threads() -> usize {
+    thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::{twos_perm_non_sym, twos_perm_sym};
+    use std::sync::Mutex;
+    use test_case::test_case;
+
+    #[test_case(true; "reflexive")]
+    #[test_case(false; "non reflexive")]
+    fn split_matches_sequential(reflexive: bool) {
+        let list = (0..13).collect::<Vec<_>>();
+        let seq = twos_perm_non_sym(&list, reflexive).collect::<Vec<_>>();
+        let seq_sym = twos_perm_sym(&list, reflexive).collect::<Vec<_>>();
+
+        for parts in 1..8 {
+            let joined = PairProducer::non_sym(&list, reflexive)
+                .split(parts)
+                .flatten()
+                .collect::<Vec<_>>();
+            assert_eq!(joined, seq);
+
+            let joined = PairProducer::sym(&list, reflexive)
+                .split(parts)
+                .flatten()
+                .collect::<Vec<_>>();
+            assert_eq!(joined, seq_sym);
+        }
+
+        let (l, r) = PairProducer::non_sym(&list, reflexive).split_at(5);
+        assert_eq!(l.len(), 5);
+        assert_eq!(l.into_iter().chain(r).collect::<Vec<_>>(), seq);
+    }
+
+    #[test]
+    fn par_fold() {
+        let list = (0..200u64).collect::<Vec<_>>();
+        let seq: u64 = twos_perm_sym(&list, true).map(|(a, b)| a * b + 1).sum();
+        for threads in [0, 1, 3, available_threads()] {
+            let par = PairProducer::sym(&list, true).par_fold(
+                threads,
+                || 0,
+                |acc, (a, b)| acc + a * b + 1,
+                |a, b| a + b,
+            );
+            assert_eq!(par, seq);
+        }
+    }
+
+    #[test]
+    fn par_for_each() {
+        let list = (0..50).collect::<Vec<_>>();
+        let seen = Mutex::new(Vec::new());
+        PairProducer::non_sym(&list, false).par_for_each(4, |p| seen.lock().unwrap().push(p));
+
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(seen, twos_perm_non_sym(&list, false).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn empty() {
+        let list: Vec<u8> = vec![];
+        let res = PairProducer::non_sym(&list, false).par_fold(4, || 0, |a, _| a + 1, |a, b| a + b);
+        assert_eq!(res, 0);
+    }
+}
@@ -0,0 +1,403 @@
+use super::{gcd, modular::pow_mod_wide, mul_mod};
+
+/// Witnesses making Miller-Rabin deterministic for all `u64`.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Size of a single segment sieved by [`Primes`].
+const SEGMENT_SIZE: u64 = 1 << 15;
+
+/// Returns `true` if `n` is a prime number. Uses a deterministic Miller-Rabin test which is exact
+/// for all `u64`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::primes::is_prime;
+/// assert!(is_prime(2));
+/// assert!(is_prime(18_446_744_073_709_551_557));
+/// assert!(!is_prime(561));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for a in WITNESSES {
+        let mut x = pow_mod_wide(a, d as u128, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Returns the prime factors of `n` in ascending order, each repeated by its multiplicity. Uses
+/// trial division for small factors and Pollard's rho for the remaining ones. 0 and 1 have no
+/// factors.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::primes::factorize;
+/// assert_eq!(factorize(360), vec![2, 2, 2, 3, 3, 5]);
+/// assert_eq!(factorize(600_851_475_143), vec![71, 839, 1471, 6857]);
+/// ```
+pub fn factorize(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+
+    for p in WITNESSES {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+
+    let mut stack = vec![n];
+    while let Some(n) = stack.pop() {
+        if n == 1 {
+            continue;
+        }
+        if is_prime(n) {
+            factors.push(n);
+            continue;
+        }
+
+        let d = pollard_rho(n);
+        stack.push(d);
+        stack.push(n / d);
+    }
+
+    factors.sort_unstable();
+    factors
+}
+
+/// Returns Euler's totient of `n`, the amount of integers in `1..=n` that are coprime to `n`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::primes::totient;
+/// assert_eq!(totient(36), 12);
+/// assert_eq!(totient(13), 12);
+/// ```
+pub fn totient(n: u64) -> u64 {
+    let mut factors = factorize(n);
+    factors.dedup();
+    factors.into_iter().fold(n, |res, p| res / p * (p - 1))
+}
+
+/// An unbounded iterator over all prime numbers in ascending order, generated by a segmented sieve
+/// of Eratosthenes. Besides a segment of fixed size, the primes up to the square root of the
+/// current segment are kept, which are sieved in segments as well. They're stored as `u32`, so
+/// starting at a large `n` needs about `4·π(√n)` bytes, e.g. around 300 KB for `n = 10^12` but
+/// 420 MB for `n = 2^62`. Use [`is_prime`] to test few large numbers instead.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::primes::Primes;
+/// let primes = Primes::new().take(6).collect::<Vec<_>>();
+/// assert_eq!(primes, vec![2, 3, 5, 7, 11, 13]);
+/// ```
+pub struct Primes {
+    /// All primes up to `base_limit`, used to sieve segments.
+    base: Vec<u32>,
+    base_limit: u64,
+    /// Start of the current segment.
+    low: u64,
+    segment: Vec<bool>,
+    pos: usize,
+}
+
+impl Primes {
+    #[inline]
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Creates a new iterator yielding all primes `>= start`.
+    pub fn starting_at(start: u64) -> Self {
+        let mut primes = Self {
+            base: Vec::new(),
+            base_limit: 0,
+            low: start,
+            segment: Vec::new(),
+            pos: 0,
+        };
+        primes.sieve_segment();
+        primes
+    }
+
+    fn sieve_segment(&mut self) {
+        let high = self.low.saturating_add(SEGMENT_SIZE);
+        self.extend_base(isqrt(high));
+        sieve_range(&mut self.segment, self.low, high, &self.base);
+        self.pos = 0;
+    }
+
+    /// Makes sure `base` holds all primes up to `limit`, which has to fit into a `u32`.
+    fn extend_base(&mut self, limit: u64) {
+        if self.base_limit >= limit {
+            return;
+        }
+
+        if self.base.is_empty() {
+            // Enough primes to sieve any following chunk of `SEGMENT_SIZE` numbers.
+            self.base_limit = limit.clamp(isqrt(SEGMENT_SIZE) + 1, SEGMENT_SIZE);
+            self.base = simple_sieve(self.base_limit as usize);
+        }
+
+        let mut chunk = Vec::new();
+        while self.base_limit < limit {
+            let low = self.base_limit + 1;
+            let high = (low + SEGMENT_SIZE).min(limit + 1);
+            sieve_range(&mut chunk, low, high, &self.base);
+            let primes = chunk.iter().enumerate().filter(|(_, p)| **p);
+            self.base
+                .extend(primes.map(|(i, _)| (low + i as u64) as u32));
+            self.base_limit = high - 1;
+        }
+    }
+}
+
+impl Default for Primes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.pos < self.segment.len() {
+                let pos = self.pos;
+                self.pos += 1;
+                if self.segment[pos] {
+                    return Some(self.low + pos as u64);
+                }
+            }
+
+            self.low = self.low.checked_add(SEGMENT_SIZE)?;
+            self.sieve_segment();
+        }
+    }
+}
+
+/// Sieves `low..high` into `segment`, which is `true` at the primes afterwards. `base` has to hold
+/// all primes up to the square root of `high`.
+fn sieve_range(segment: &mut Vec<bool>, low: u64, high: u64, base: &[u32]) {
+    segment.clear();
+    segment.resize((high - low) as usize, true);
+
+    for i in low..high.min(2) {
+        segment[(i - low) as usize] = false;
+    }
+
+    for &p in base {
+        let p = p as u64;
+        if p.saturating_mul(p) >= high {
+            break;
+        }
+        let first = (p * p).max(low.div_ceil(p).saturating_mul(p));
+        for m in (first..high).step_by(p as usize) {
+            segment[(m - low) as usize] = false;
+        }
+    }
+}
+
+/// Returns all primes `<= limit` using a plain sieve of Eratosthenes.
+fn simple_sieve(limit: usize) -> Vec<u32> {
+    let mut is_prime = vec![true; limit + 1];
+    let mut primes = Vec::new();
+
+    for i in 2..=limit {
+        if !is_prime[i] {
+            continue;
+        }
+        primes.push(i as u32);
+        for m in (i * i..=limit).step_by(i) {
+            is_prime[m] = false;
+        }
+    }
+
+    primes
+}
+
+/// Finds a non trivial divisor of the odd composite `n` using Brent's variant of Pollard's rho.
+fn pollard_rho(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+
+        let (mut x, mut y, mut ys) = (0, 2, 2);
+        let (mut g, mut r, mut q) = (1, 1, 1);
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        if g == n {
+            // The batch overshot, redo it step by step.
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r.checked_mul(r).is_none_or(|sq| sq > n) {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|sq| sq <= n) {
+        r += 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn naive_is_prime(n: u64) -> bool {
+        n >= 2
+            && (2..)
+                .take_while(|i| i * i <= n)
+                .all(|i| !n.is_multiple_of(i))
+    }
+
+    #[test]
+    fn is_prime_small() {
+        for n in 0..10_000 {
+            assert_eq!(is_prime(n), naive_is_prime(n), "{n}");
+        }
+    }
+
+    #[test_case(18_446_744_073_709_551_557, true; "largest u64 prime")]
+    #[test_case(3_215_031_751, false; "strong pseudoprime to 2 3 5 7")]
+    #[test_case(3_825_123_056_546_413_051, false; "strong pseudoprime to first 9 primes")]
+    #[test_case(u64::MAX, false; "max")]
+    #[test_case(1_000_000_007, true; "1e9+7")]
+    fn is_prime_large(n: u64, exp: bool) {
+        assert_eq!(is_prime(n), exp);
+    }
+
+    #[test]
+    fn sieve_matches_naive() {
+        let sieved = Primes::new()
+            .take_while(|p| *p < 100_000)
+            .collect::<Vec<_>>();
+        let naive = (0..100_000)
+            .filter(|i| naive_is_prime(*i))
+            .collect::<Vec<_>>();
+        assert_eq!(sieved, naive);
+    }
+
+    #[test]
+    fn sieve_starting_at() {
+        let start = 1_000_000_000_000;
+        let sieved = Primes::starting_at(start).take(50).collect::<Vec<_>>();
+        let exp = (start..)
+            .filter(|i| is_prime(*i))
+            .take(50)
+            .collect::<Vec<_>>();
+        assert_eq!(sieved, exp);
+    }
+
+    #[test]
+    fn sieve_base_in_segments() {
+        // Needs base primes beyond the first chunk sieved without segments.
+        let start = 1 << 40;
+        let mut primes = Primes::starting_at(start);
+        let sieved = primes.by_ref().take(20).collect::<Vec<_>>();
+        let exp = (start..)
+            .filter(|i| is_prime(*i))
+            .take(20)
+            .collect::<Vec<_>>();
+        assert_eq!(sieved, exp);
+        assert_eq!(primes.base_limit, isqrt(start + SEGMENT_SIZE));
+        assert!(primes.base.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(primes.base.len(), 82_025);
+    }
+
+    #[test_case(0, vec![]; "zero")]
+    #[test_case(1, vec![]; "one")]
+    #[test_case(97, vec![97]; "prime")]
+    #[test_case(1 << 40, vec![2; 40]; "power of two")]
+    #[test_case(999_999_000_001 * 1_000_003, vec![1_000_003, 999_999_000_001]; "semiprime")]
+    #[test_case(4_294_967_291 * 4_294_967_279, vec![4_294_967_279, 4_294_967_291]; "large semiprime")]
+    fn test_factorize(n: u64, exp: Vec<u64>) {
+        assert_eq!(factorize(n), exp);
+    }
+
+    #[test]
+    fn factorize_product() {
+        for n in (1..u64::MAX).step_by(u64::MAX as usize / 997).take(200) {
+            let factors = factorize(n);
+            assert!(factors.iter().all(|f| is_prime(*f)));
+            assert_eq!(factors.iter().product::<u64>(), n);
+        }
+    }
+
+    #[test_case(0, 0; "zero")]
+    #[test_case(1, 1; "one")]
+    #[test_case(9, 6; "square")]
+    #[test_case(1_000_000_007, 1_000_000_006; "prime")]
+    fn test_totient(n: u64, exp: u64) {
+        assert_eq!(totient(n), exp);
+    }
+}
@@ -0,0 +1,401 @@
+use super::{
+    checked_gcd, gcd,
+    num::{Integer, Signed},
+};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A rational number `numer / denom`. Values are always kept normalized: the fraction is fully
+/// reduced and the denominator is positive, so equal values have equal representations.
+///
+/// The operators panic on overflow, use the `checked_*` methods to handle it.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::rational::Ratio;
+/// let a = Ratio::new(1, 3);
+/// let b = Ratio::new(1, 6);
+/// assert_eq!(a + b, Ratio::new(1, 2));
+/// assert_eq!((a * b).to_string(), "1/18");
+/// assert_eq!("-4/6".parse::<Ratio<i32>>(), Ok(Ratio::new(-2, 3)));
+/// assert!(Ratio::new(2, 3) > Ratio::new(3, 5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Creates a new normalized ratio. Panics if `denom` is zero or normalizing overflows.
+    #[inline]
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "denominator is zero");
+        Self::checked_new(numer, denom).expect("normalizing ratio overflowed")
+    }
+
+    /// Creates a new normalized ratio. Returns `None` if `denom` is zero or normalizing overflows.
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+
+        // The gcd is only unrepresentable if `denom` is `MIN` and `numer` is `MIN` or zero. Dividing
+        // by `denom` itself normalizes those to 1/1 and 0/1.
+        let g = checked_gcd(numer, denom).unwrap_or(denom);
+
+        let (mut numer, mut denom) = (numer / g, denom / g);
+        if denom < T::zero() {
+            numer = T::zero().checked_sub(numer)?;
+            denom = T::zero().checked_sub(denom)?;
+        }
+
+        Some(Self { numer, denom })
+    }
+
+    #[inline]
+    pub fn from_integer(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::one(),
+        }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+
+    #[inline]
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    #[inline]
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    /// Returns the integer part, rounding towards zero.
+    #[inline]
+    pub fn trunc(&self) -> T {
+        self.numer / self.denom
+    }
+
+    /// Returns `1 / self` or `None` if `self` is zero.
+    #[inline]
+    pub fn recip(&self) -> Option<Self> {
+        Self::checked_new(self.denom, self.numer)
+    }
+
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.numer.to_f64() / self.denom.to_f64()
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let (l, r) = (self.denom / g, rhs.denom / g);
+        let numer = self
+            .numer
+            .checked_mul(r)?
+            .checked_add(rhs.numer.checked_mul(l)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(r)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let (l, r) = (self.denom / g, rhs.denom / g);
+        let numer = self
+            .numer
+            .checked_mul(r)?
+            .checked_sub(rhs.numer.checked_mul(l)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(r)?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Reduce crosswise first to keep the products small.
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        let numer = (self.numer / g1).checked_mul(rhs.numer / g2)?;
+        let denom = (self.denom / g2).checked_mul(rhs.denom / g1)?;
+        Self::checked_new(numer, denom)
+    }
+
+    /// Returns `None` on overflow or if `rhs` is zero.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+}
+
+impl<T: Integer + Signed> Ratio<T> {
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numer: T::zero().checked_sub(self.numer)?,
+            denom: self.denom,
+        })
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.numer < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Integer> Default for Ratio<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<T: Integer> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compares the continued fraction expansions, which can't overflow unlike comparing the
+        // cross products.
+        let (mut a, mut b) = (self.numer, self.denom);
+        let (mut c, mut d) = (other.numer, other.denom);
+        let mut reversed = false;
+
+        loop {
+            let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+            let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+
+            let ord = match (q1.cmp(&q2), r1.is_zero(), r2.is_zero()) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // r1/b < r2/d <=> b/r1 > d/r2
+                    (a, b, c, d) = (b, r1, d, r2);
+                    reversed = !reversed;
+                    continue;
+                }
+                (ord, _, _) => ord,
+            };
+
+            return if reversed { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Display for Ratio<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// Error returned when parsing a [`Ratio`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRatioError {
+    /// The numerator or denominator isn't a valid integer.
+    Invalid,
+    /// The denominator is zero.
+    ZeroDenominator,
+}
+
+impl Display for ParseRatioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRatioError::Invalid => write!(f, "invalid ratio"),
+            ParseRatioError::ZeroDenominator => write!(f, "denominator is zero"),
+        }
+    }
+}
+
+impl Error for ParseRatioError {}
+
+impl<T: Integer + FromStr> FromStr for Ratio<T> {
+    type Err = ParseRatioError;
+
+    /// Parses either `numer/denom` or a single integer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| s.trim().parse::<T>().map_err(|_| ParseRatioError::Invalid);
+
+        match s.split_once('/') {
+            Some((numer, denom)) => {
+                let denom = parse(denom)?;
+                if denom.is_zero() {
+                    return Err(ParseRatioError::ZeroDenominator);
+                }
+                Self::checked_new(parse(numer)?, denom).ok_or(ParseRatioError::Invalid)
+            }
+            None => Ok(Self::from_integer(parse(s)?)),
+        }
+    }
+}
+
+macro_rules! impl_op {
+    ($($tr:ident $f:ident $checked:ident $msg:literal, $tr_assign:ident $f_assign:ident),*) => {$(
+        impl<T: Integer> $tr for Ratio<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<T: Integer> $tr_assign for Ratio<T> {
+            #[inline]
+            fn $f_assign(&mut self, rhs: Self) {
+                *self = self.$f(rhs);
+            }
+        }
+    )*};
+}
+
+impl_op!(
+    Add add checked_add "attempt to add with overflow", AddAssign add_assign,
+    Sub sub checked_sub "attempt to subtract with overflow", SubAssign sub_assign,
+    Mul mul checked_mul "attempt to multiply with overflow", MulAssign mul_assign,
+    Div div checked_div "attempt to divide by zero or with overflow", DivAssign div_assign
+);
+
+impl<T: Integer + Signed> Neg for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2, 4, 1, 2; "reduce")]
+    #[test_case(3, -6, -1, 2; "negative denominator")]
+    #[test_case(-3, -6, 1, 2; "both negative")]
+    #[test_case(0, -5, 0, 1; "zero")]
+    fn normalize(n: i32, d: i32, en: i32, ed: i32) {
+        let r = Ratio::new(n, d);
+        assert_eq!((r.numer(), r.denom()), (en, ed));
+    }
+
+    #[test_case(i8::MIN, i8::MIN, Some((1, 1)); "min over min")]
+    #[test_case(i8::MIN, 1, Some((i8::MIN, 1)); "min over one")]
+    #[test_case(0, i8::MIN, Some((0, 1)); "zero over min")]
+    #[test_case(i8::MIN, 2, Some((-64, 1)); "min over two")]
+    #[test_case(1, i8::MIN, None; "one over min")]
+    #[test_case(i8::MIN, -1, None; "min over minus one")]
+    fn normalize_min(n: i8, d: i8, exp: Option<(i8, i8)>) {
+        let r = Ratio::checked_new(n, d);
+        assert_eq!(r.map(|r| (r.numer(), r.denom())), exp);
+        assert_eq!(format!("{}/{}", n, d).parse::<Ratio<i8>>().ok(), r);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Ratio::new(3, 4);
+        let b = Ratio::new(-5, 6);
+        assert_eq!(a + b, Ratio::new(-1, 12));
+        assert_eq!(a - b, Ratio::new(19, 12));
+        assert_eq!(a * b, Ratio::new(-5, 8));
+        assert_eq!(a / b, Ratio::new(-9, 10));
+        assert_eq!(-a, Ratio::new(-3, 4));
+
+        let mut c = Ratio::from(1u8);
+        c += Ratio::new(1, 2);
+        assert_eq!(c, Ratio::new(3, 2));
+        assert_eq!(Ratio::new(1u8, 2).checked_sub(Ratio::new(2, 3)), None);
+    }
+
+    #[test]
+    fn overflow() {
+        let big = Ratio::new(i64::MAX, 2);
+        assert_eq!(big.checked_add(big), None);
+        assert_eq!(big.checked_mul(Ratio::from(3)), None);
+        assert_eq!(Ratio::checked_new(i8::MIN, -1), None);
+        assert_eq!(Ratio::<i32>::one().checked_div(Ratio::zero()), None);
+        // Cross reduction avoids overflowing intermediates.
+        let a = Ratio::new(i64::MAX, 3);
+        assert_eq!(a * Ratio::new(3, i64::MAX), Ratio::one());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Ratio::new(1, 3) < Ratio::new(1, 2));
+        assert!(Ratio::new(-1, 2) < Ratio::new(-1, 3));
+        // Cross products of these would overflow.
+        assert!(Ratio::new(i64::MAX - 1, i64::MAX) > Ratio::new(i64::MAX - 2, i64::MAX - 1));
+        assert!(Ratio::new(i64::MAX, i64::MAX - 1) < Ratio::new(i64::MAX - 1, i64::MAX - 2));
+        assert_eq!(Ratio::new(2, 4).cmp(&Ratio::new(1, 2)), Ordering::Equal);
+        let mut v = vec![
+            Ratio::new(3, 4),
+            Ratio::new(-1, 2),
+            Ratio::new(2, 3),
+            Ratio::from(1),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            vec![
+                Ratio::new(-1, 2),
+                Ratio::new(2, 3),
+                Ratio::new(3, 4),
+                Ratio::from(1)
+            ]
+        );
+    }
+
+    #[test_case("3/4", Ok(Ratio::new(3, 4)); "fraction")]
+    #[test_case(" -2 / 8 ", Ok(Ratio::new(-1, 4)); "whitespace")]
+    #[test_case("7", Ok(Ratio::from(7)); "integer")]
+    #[test_case("1/0", Err(ParseRatioError::ZeroDenominator); "zero denominator")]
+    #[test_case("a/2", Err(ParseRatioError::Invalid); "invalid")]
+    #[test_case("-2147483648/-2147483648", Ok(Ratio::one()); "min over min")]
+    #[test_case("-2147483648/1", Ok(Ratio::from(i32::MIN)); "min over one")]
+    #[test_case("1/-2147483648", Err(ParseRatioError::Invalid); "unrepresentable")]
+    fn parse(s: &str, exp: Result<Ratio<i32>, ParseRatioError>) {
+        assert_eq!(s.parse::<Ratio<i32>>(), exp);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Ratio::new(6, -4).to_string(), "-3/2");
+        assert_eq!(Ratio::new(4, 2).to_string(), "2");
+    }
+}
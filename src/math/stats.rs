@@ -0,0 +1,426 @@
+use super::num::Num;
+use std::iter::FromIterator;
+
+/// A streaming accumulator for descriptive statistics. Values are added one at a time and mean
+/// and variance are updated using Welford's algorithm, so no samples have to be kept in memory.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::stats::Stats;
+/// let stats = [2, 4, 4, 4, 5, 5, 7, 9].iter().copied().collect::<Stats>();
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.0));
+/// assert_eq!(stats.variance(), Some(4.0));
+/// assert_eq!(stats.min(), Some(2.0));
+/// assert_eq!(stats.max(), Some(9.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Stats {
+    count: u64,
+    mean: f64,
+    /// Sum of squared differences from the current mean.
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Stats {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a single value to the accumulator.
+    pub fn push<T: Num>(&mut self, value: T) {
+        let value = value.to_f64();
+
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Combines the values of `other` into `self`, as if all values of `other` were pushed to
+    /// `self`.
+    pub fn merge(&mut self, other: &Stats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Returns the amount of values pushed.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then_some(self.mean)
+    }
+
+    /// Returns the population variance.
+    #[inline]
+    pub fn variance(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance, using Bessel's correction. Requires at least two values.
+    #[inline]
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation.
+    #[inline]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the sample standard deviation. Requires at least two values.
+    #[inline]
+    pub fn sample_std_dev(&self) -> Option<f64> {
+        self.sample_variance().map(f64::sqrt)
+    }
+
+    #[inline]
+    pub fn min(&self) -> Option<f64> {
+        (!self.is_empty()).then_some(self.min)
+    }
+
+    #[inline]
+    pub fn max(&self) -> Option<f64> {
+        (!self.is_empty()).then_some(self.max)
+    }
+
+    /// Returns the sum of all values.
+    #[inline]
+    pub fn sum(&self) -> f64 {
+        self.mean * self.count as f64
+    }
+}
+
+impl<T: Num> Extend<T> for Stats {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.push(i);
+        }
+    }
+}
+
+impl<T: Num> FromIterator<T> for Stats {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stats = Stats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Returns the exact `p`-th percentile (`0.0..=100.0`) of `data`, linearly interpolating between
+/// the two closest ranks. Returns `None` if `data` is empty. `data` doesn't have to be sorted.
+/// Panics if `p` is outside of `0.0..=100.0`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::stats::percentile;
+/// assert_eq!(percentile(&[4, 1, 3, 2], 50.0), Some(2.5));
+/// assert_eq!(percentile(&[4, 1, 3, 2], 100.0), Some(4.0));
+/// ```
+#[inline]
+pub fn percentile<T: Num>(data: &[T], p: f64) -> Option<f64> {
+    percentiles(data, &[p]).pop()
+}
+
+/// Returns the exact percentiles for each value in `ps`, sorting `data` only once. Returns an empty
+/// vector if `data` is empty. Panics if any value in `ps` is outside of `0.0..=100.0`.
+pub fn percentiles<T: Num>(data: &[T], ps: &[f64]) -> Vec<f64> {
+    let mut sorted = data.iter().map(|i| i.to_f64()).collect::<Vec<_>>();
+    sorted.sort_unstable_by(f64::total_cmp);
+    ps.iter()
+        .filter_map(|p| sorted_percentile(&sorted, *p))
+        .collect()
+}
+
+/// Returns the median of `data`, or `None` if `data` is empty.
+#[inline]
+pub fn median<T: Num>(data: &[T]) -> Option<f64> {
+    percentile(data, 50.0)
+}
+
+/// Returns the `p`-th percentile of already sorted `data`. Panics if `p` is outside of
+/// `0.0..=100.0`.
+pub fn sorted_percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    assert!((0.0..=100.0).contains(&p), "percentile out of range");
+
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64))
+}
+
+/// An approximate streaming quantile sketch. Values are kept in a hierarchy of compactors, each
+/// holding at most `k` values; once a compactor is full it is sorted and every second value is
+/// promoted to the next level with doubled weight. Memory is bounded by `O(k * log(n / k))` and the
+/// rank error shrinks with growing `k`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::math::stats::QuantileSketch;
+/// let mut sketch = QuantileSketch::new(200);
+/// for i in 0..100_000 {
+///     sketch.push(i);
+/// }
+/// let median = sketch.quantile(0.5).unwrap();
+/// assert!((median - 50_000.0).abs() < 2_000.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuantileSketch {
+    k: usize,
+    levels: Vec<Vec<f64>>,
+    count: u64,
+    /// Alternates which half of a compacted level gets promoted, to avoid a systematic bias.
+    offset: bool,
+}
+
+impl QuantileSketch {
+    /// Creates a new sketch with `k` values per level. Needs `k >= 2`.
+    pub fn new(k: usize) -> Self {
+        assert!(k >= 2);
+        Self {
+            k,
+            levels: vec![Vec::with_capacity(k)],
+            count: 0,
+            offset: false,
+        }
+    }
+
+    pub fn push<T: Num>(&mut self, value: T) {
+        self.count += 1;
+        self.levels[0].push(value.to_f64());
+        if self.levels[0].len() >= self.k {
+            self.compact(0);
+        }
+    }
+
+    /// Adds all values of `other` to `self`.
+    pub fn merge(&mut self, other: &QuantileSketch) {
+        self.count += other.count;
+        for (level, values) in other.levels.iter().enumerate() {
+            if self.levels.len() <= level {
+                self.levels.push(Vec::with_capacity(self.k));
+            }
+            self.levels[level].extend_from_slice(values);
+        }
+
+        for level in 0..self.levels.len() {
+            if self.levels[level].len() >= self.k {
+                self.compact(level);
+            }
+        }
+    }
+
+    /// Returns the amount of values pushed.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the approximate `q`-quantile (`0.0..=1.0`) or `None` if the sketch is empty. Panics
+    /// if `q` is outside of `0.0..=1.0`.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        assert!((0.0..=1.0).contains(&q), "quantile out of range");
+
+        let mut weighted = self
+            .levels
+            .iter()
+            .enumerate()
+            .flat_map(|(level, values)| values.iter().map(move |v| (*v, 1u64 << level)))
+            .collect::<Vec<_>>();
+        weighted.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let total: u64 = weighted.iter().map(|i| i.1).sum();
+        let target = (q * total as f64).ceil().max(1.0) as u64;
+
+        let mut seen = 0;
+        for (value, weight) in &weighted {
+            seen += weight;
+            if seen >= target {
+                return Some(*value);
+            }
+        }
+
+        weighted.last().map(|i| i.0)
+    }
+
+    fn compact(&mut self, level: usize) {
+        if self.levels.len() <= level + 1 {
+            self.levels.push(Vec::with_capacity(self.k));
+        }
+
+        let values = &mut self.levels[level];
+        values.sort_unstable_by(f64::total_cmp);
+
+        // Keep the largest value on odd lengths so that pairs are promoted as a whole.
+        let rest = (values.len() % 2 == 1).then(|| values.pop().unwrap());
+        let start = usize::from(self.offset);
+        self.offset = !self.offset;
+
+        let promoted = values
+            .iter()
+            .skip(start)
+            .step_by(2)
+            .copied()
+            .collect::<Vec<_>>();
+        values.clear();
+        values.extend(rest);
+
+        self.levels[level + 1].extend(promoted);
+        if self.levels[level + 1].len() >= self.k {
+            self.compact(level + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn stats_empty() {
+        let stats = Stats::new();
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.min(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "itertools")]
+    fn stats_iter_ext() {
+        use crate::itertools::IterExt;
+
+        let stats = (1..=10u8).stats();
+        assert_eq!(stats.count(), 10);
+        assert_eq!(stats.mean(), Some(5.5));
+        assert!(close(stats.variance().unwrap(), 8.25));
+        assert!(close(stats.sample_variance().unwrap(), 82.5 / 9.0));
+        assert_eq!(stats.sum(), 55.0);
+    }
+
+    #[test]
+    fn stats_merge() {
+        let data = (0..1000)
+            .map(|i| ((i * 7919) % 1000) as f64 / 3.0)
+            .collect::<Vec<_>>();
+        let all = data.iter().copied().collect::<Stats>();
+
+        let mut merged = data[..300].iter().copied().collect::<Stats>();
+        merged.merge(&data[300..].iter().copied().collect::<Stats>());
+
+        assert_eq!(merged.count(), all.count());
+        assert!(close(merged.mean().unwrap(), all.mean().unwrap()));
+        assert!(close(merged.variance().unwrap(), all.variance().unwrap()));
+        assert_eq!(merged.min(), all.min());
+        assert_eq!(merged.max(), all.max());
+    }
+
+    #[test_case(&[], 50.0, None; "empty")]
+    #[test_case(&[3.0], 10.0, Some(3.0); "single")]
+    #[test_case(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.0, Some(1.0); "min")]
+    #[test_case(&[1.0, 2.0, 3.0, 4.0, 5.0], 25.0, Some(2.0); "quartile")]
+    #[test_case(&[5.0, 1.0, 4.0, 2.0], 50.0, Some(3.0); "unsorted median")]
+    #[test_case(&[1.0, 2.0], 90.0, Some(1.9); "interpolated")]
+    fn test_percentile(data: &[f64], p: f64, exp: Option<f64>) {
+        let res = percentile(data, p);
+        assert_eq!(res.is_some(), exp.is_some());
+        if let (Some(res), Some(exp)) = (res, exp) {
+            assert!(close(res, exp));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile out of range")]
+    fn percentile_out_of_range() {
+        percentile(&[1.0, 2.0], 101.0);
+    }
+
+    #[test]
+    fn sketch_accuracy() {
+        let n = 200_000u64;
+        let mut sketch = QuantileSketch::new(256);
+        // Pseudo random permutation of 0..n
+        for i in 0..n {
+            sketch.push((i * 48_271) % n);
+        }
+
+        assert_eq!(sketch.count(), n);
+        let stored: usize = sketch.levels.iter().map(|l| l.len()).sum();
+        assert!(stored < 256 * sketch.levels.len());
+
+        for q in [0.01, 0.1, 0.5, 0.9, 0.99] {
+            let est = sketch.quantile(q).unwrap();
+            let exp = q * n as f64;
+            assert!(
+                (est - exp).abs() < n as f64 * 0.02,
+                "{}: {} vs {}",
+                q,
+                est,
+                exp
+            );
+        }
+    }
+
+    #[test]
+    fn sketch_merge() {
+        let mut a = QuantileSketch::new(64);
+        let mut b = QuantileSketch::new(64);
+        for i in 0..5000 {
+            a.push(i);
+            b.push(i + 5000);
+        }
+        a.merge(&b);
+        assert_eq!(a.count(), 10_000);
+        let median = a.quantile(0.5).unwrap();
+        assert!((median - 5000.0).abs() < 500.0);
+        assert_eq!(QuantileSketch::new(4).quantile(0.5), None);
+    }
+}
@@ -0,0 +1,294 @@
+pub mod weighted;
+
+pub use weighted::{choose_weighted, WeightError, WeightedIndex};
+
+use crate::math::pairs::{pair_count_non_sym, pair_count_sym, unrank_non_sym, unrank_sym};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::SystemTime,
+};
+
+/// A source of random numbers. Only [`Rng::next_u64`] has to be implemented, so any generator can
+/// be plugged into the functions of this module.
+pub trait Rng {
+    /// Returns the next uniformly distributed random `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a uniformly distributed number in `0..n` without modulo bias. Panics if `n` is zero.
+    fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+
+        // Lemire's nearly divisionless method.
+        let mut m = self.next_u64() as u128 * n as u128;
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * n as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    /// Same as [`Rng::below`] for `usize`, handy to pick an index.
+    #[inline]
+    fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    /// Returns a uniformly distributed `f64` in `0.0..1.0`.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns `true` with probability `p`.
+    #[inline]
+    fn chance(&mut self, p: f64) -> bool {
+        self.next_f64() < p
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// The xoshiro256++ generator. It's fast, small and has good statistical quality, but it is not
+/// cryptographically secure.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::random::{Rng, Xoshiro256};
+/// let mut a = Xoshiro256::seed_from_u64(42);
+/// let mut b = Xoshiro256::seed_from_u64(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!(a.below(6) < 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    /// Creates a generator from its raw state. Panics if the state is all zero, as the generator
+    /// would only return zeros.
+    #[inline]
+    pub fn from_state(s: [u64; 4]) -> Self {
+        assert!(s != [0; 4], "state must not be all zero");
+        Self { s }
+    }
+
+    /// Creates a generator whose state is derived from `seed` using SplitMix64, so similar seeds
+    /// still result in unrelated sequences.
+    pub fn seed_from_u64(mut seed: u64) -> Self {
+        let mut next = || {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self {
+            s: [next(), next(), next(), next()],
+        }
+    }
+
+    /// Creates a generator with a non-deterministic seed taken from the randomly keyed std hasher
+    /// and the current time.
+    pub fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        if let Ok(time) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            hasher.write_u128(time.as_nanos());
+        }
+        Self::seed_from_u64(hasher.finish())
+    }
+}
+
+impl Rng for Xoshiro256 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// Shuffles `slice` in place using the Fisher–Yates algorithm. Every permutation is equally likely.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::random::{shuffle, Xoshiro256};
+/// let mut v = (0..10).collect::<Vec<_>>();
+/// shuffle(&mut v, &mut Xoshiro256::seed_from_u64(7));
+/// v.sort_unstable();
+/// assert_eq!(v, (0..10).collect::<Vec<_>>());
+/// ```
+pub fn shuffle<T, R: Rng>(slice: &mut [T], rng: &mut R) {
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, rng.index(i + 1));
+    }
+}
+
+/// Returns a uniformly chosen element of `slice` or `None` if it's empty.
+#[inline]
+pub fn choose<'a, T, R: Rng>(slice: &'a [T], rng: &mut R) -> Option<&'a T> {
+    if slice.is_empty() {
+        return None;
+    }
+    Some(&slice[rng.index(slice.len())])
+}
+
+/// Returns `k` uniformly chosen items of `iter` in a single pass, or all items if there are fewer
+/// than `k` (reservoir sampling). The order of the returned items is unspecified.
+pub fn reservoir_sample<I, R>(iter: I, k: usize, rng: &mut R) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    R: Rng,
+{
+    let mut iter = iter.into_iter();
+    let mut reservoir = iter.by_ref().take(k).collect::<Vec<_>>();
+    if reservoir.len() < k {
+        return reservoir;
+    }
+
+    for (seen, item) in (k + 1..).zip(iter) {
+        let idx = rng.index(seen);
+        if idx < k {
+            reservoir[idx] = item;
+        }
+    }
+
+    reservoir
+}
+
+/// Returns a uniformly chosen pair out of all pairs yielded by
+/// [`twos_perm_non_sym`](crate::math::twos_perm_non_sym), or `None` if there are none. Picks the
+/// `k`-th pair of that ordering for a random `k`, so using the same generator state always
+/// selects the same pair.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::random::{choose_pair, Xoshiro256};
+/// let list = ["a", "b", "c"];
+/// let (a, b) = choose_pair(&list, false, &mut Xoshiro256::seed_from_u64(1)).unwrap();
+/// assert!(a < b);
+/// ```
+pub fn choose_pair<'a, T, R: Rng>(
+    list: &'a [T],
+    reflexive: bool,
+    rng: &mut R,
+) -> Option<(&'a T, &'a T)> {
+    let count = pair_count_non_sym(list.len(), reflexive);
+    if count == 0 {
+        return None;
+    }
+    let (i, j) = unrank_non_sym(list.len(), rng.index(count), reflexive)?;
+    Some((&list[i], &list[j]))
+}
+
+/// Same as [`choose_pair`] but chooses out of the pairs yielded by
+/// [`twos_perm_sym`](crate::math::twos_perm_sym).
+pub fn choose_pair_sym<'a, T, R: Rng>(
+    list: &'a [T],
+    reflexive: bool,
+    rng: &mut R,
+) -> Option<(&'a T, &'a T)> {
+    let count = pair_count_sym(list.len(), reflexive);
+    if count == 0 {
+        return None;
+    }
+    let (i, j) = unrank_sym(list.len(), rng.index(count), reflexive)?;
+    Some((&list[i], &list[j]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::twos_perm_non_sym;
+
+    #[test]
+    fn reference_output() {
+        let mut rng = Xoshiro256::from_state([1, 2, 3, 4]);
+        let out = (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            vec![41943041, 58720359, 3588806011781223, 3591011842654386]
+        );
+    }
+
+    #[test]
+    fn below_is_uniform() {
+        let mut rng = Xoshiro256::seed_from_u64(0);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[rng.below(6) as usize] += 1;
+        }
+        assert!(counts.iter().all(|c| (9_500..10_500).contains(c)));
+        assert!((0..1000).all(|_| rng.next_f64() < 1.0));
+    }
+
+    #[test]
+    fn shuffle_deterministic() {
+        let shuffled = |seed| {
+            let mut v = (0..20).collect::<Vec<_>>();
+            shuffle(&mut v, &mut Xoshiro256::seed_from_u64(seed));
+            v
+        };
+        assert_eq!(shuffled(3), shuffled(3));
+        assert_ne!(shuffled(3), shuffled(4));
+        assert_ne!(shuffled(3), (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reservoir() {
+        let mut rng = Xoshiro256::seed_from_u64(9);
+        assert_eq!(reservoir_sample(0..3, 5, &mut rng), vec![0, 1, 2]);
+        assert!(reservoir_sample(0..3, 0, &mut rng).is_empty());
+
+        let mut counts = [0u32; 10];
+        for _ in 0..10_000 {
+            for i in reservoir_sample(0..10, 3, &mut rng) {
+                counts[i] += 1;
+            }
+        }
+        assert!(counts.iter().all(|c| (2_700..3_300).contains(c)));
+    }
+
+    #[test]
+    fn pairs_follow_twos_perm() {
+        let list = [1, 2, 3, 4];
+        let pairs = twos_perm_non_sym(&list, false).collect::<Vec<_>>();
+
+        let mut a = Xoshiro256::seed_from_u64(5);
+        let mut b = a.clone();
+        for _ in 0..50 {
+            let (x, y) = choose_pair(&list, false, &mut a).unwrap();
+            assert_eq!(pairs[b.index(pairs.len())], (x, y));
+        }
+
+        assert_eq!(choose_pair(&[1], false, &mut a), None);
+        assert_eq!(choose_pair(&[1], true, &mut a), Some((&1, &1)));
+        let (x, y) = choose_pair_sym(&list, false, &mut a).unwrap();
+        assert_ne!(x, y);
+    }
+}
@@ -0,0 +1,149 @@
+use super::Rng;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Error returned by [`WeightedIndex::new`] for weights which can't be sampled from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightError {
+    /// No weights were given.
+    Empty,
+    /// A weight is negative, infinite or NaN.
+    InvalidWeight,
+    /// All weights are zero.
+    AllZero,
+}
+
+impl Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::Empty => write!(f, "no weights given"),
+            WeightError::InvalidWeight => write!(f, "weight is negative or not finite"),
+            WeightError::AllZero => write!(f, "all weights are zero"),
+        }
+    }
+}
+
+impl Error for WeightError {}
+
+/// Samples indices with a probability proportional to their weight. Building it takes O(n) and
+/// every sample O(log n), so it's worth keeping around when sampling repeatedly.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::random::{WeightedIndex, Xoshiro256};
+/// let dist = WeightedIndex::new(&[0.0, 3.0, 1.0]).unwrap();
+/// let mut rng = Xoshiro256::seed_from_u64(3);
+/// assert_ne!(dist.sample(&mut rng), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+    cumulative: Vec<f64>,
+}
+
+impl WeightedIndex {
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        if weights.is_empty() {
+            return Err(WeightError::Empty);
+        }
+
+        let mut total = 0.0;
+        let mut cumulative = Vec::with_capacity(weights.len());
+        for w in weights {
+            if !w.is_finite() || *w < 0.0 {
+                return Err(WeightError::InvalidWeight);
+            }
+            total += w;
+            cumulative.push(total);
+        }
+
+        if total == 0.0 {
+            return Err(WeightError::AllZero);
+        }
+        if !total.is_finite() {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        Ok(Self { cumulative })
+    }
+
+    /// Returns a random index.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let x = rng.next_f64() * total;
+        // Indices with a zero weight have the same cumulative weight as their predecessor and are
+        // skipped. Clamp in case of rounding at the upper end.
+        let idx = self.cumulative.partition_point(|c| *c <= x);
+        idx.min(self.cumulative.len() - 1)
+    }
+
+    /// Returns the amount of weights.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cumulative.len()
+    }
+
+    /// Always `false`, as a `WeightedIndex` can't be built from no weights.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cumulative.is_empty()
+    }
+}
+
+/// Returns an element of `slice` chosen with a probability proportional to the weight returned by
+/// `weight`. Use a [`WeightedIndex`] to sample from the same slice repeatedly.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::random::{choose_weighted, Xoshiro256};
+/// let items = [("rare", 0.1), ("common", 5.0)];
+/// let mut rng = Xoshiro256::seed_from_u64(8);
+/// let (name, _) = choose_weighted(&items, |i| i.1, &mut rng).unwrap();
+/// assert!(name == &"rare" || name == &"common");
+/// ```
+pub fn choose_weighted<'a, T, F, R>(
+    slice: &'a [T],
+    weight: F,
+    rng: &mut R,
+) -> Result<&'a T, WeightError>
+where
+    F: FnMut(&T) -> f64,
+    R: Rng,
+{
+    let weights = slice.iter().map(weight).collect::<Vec<_>>();
+    let dist = WeightedIndex::new(&weights)?;
+    Ok(&slice[dist.sample(rng)])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::random::Xoshiro256;
+    use test_case::test_case;
+
+    #[test_case(&[], WeightError::Empty; "empty")]
+    #[test_case(&[1.0, -1.0], WeightError::InvalidWeight; "negative")]
+    #[test_case(&[1.0, f64::NAN], WeightError::InvalidWeight; "nan")]
+    #[test_case(&[f64::MAX, f64::MAX], WeightError::InvalidWeight; "overflow")]
+    #[test_case(&[0.0, 0.0], WeightError::AllZero; "all zero")]
+    fn invalid(weights: &[f64], err: WeightError) {
+        assert_eq!(WeightedIndex::new(weights), Err(err));
+    }
+
+    #[test]
+    fn proportional() {
+        let dist = WeightedIndex::new(&[1.0, 0.0, 3.0, 0.0]).unwrap();
+        let mut rng = Xoshiro256::seed_from_u64(11);
+        let mut counts = [0u32; 4];
+        for _ in 0..40_000 {
+            counts[dist.sample(&mut rng)] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert_eq!(counts[3], 0);
+        assert!((9_500..10_500).contains(&counts[0]));
+        assert!((29_500..30_500).contains(&counts[2]));
+    }
+}
@@ -0,0 +1,210 @@
+/// Splits an identifier or phrase into its words. Words are separated by whitespace, punctuation
+/// and symbols and by changes in case: a new word starts at an uppercase letter following a
+/// lowercase letter or digit, and at the last uppercase letter of an acronym followed by a
+/// lowercase letter. Digits belong to the word they follow. All other characters, like combining
+/// marks of decomposed (NFD) text, stay inside their word.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::stringtools::words;
+/// assert_eq!(words("HTTPServer"), vec!["HTTP", "Server"]);
+/// assert_eq!(words("parse_utf8Bytes"), vec!["parse", "utf8", "Bytes"]);
+/// assert_eq!(words("  kebab-case  "), vec!["kebab", "case"]);
+/// assert_eq!(words("Cafe\u{301}Name"), vec!["Cafe\u{301}", "Name"]);
+/// ```
+pub fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in s.split(is_separator).filter(|p| !p.is_empty()) {
+        // Case changes are only checked between letters and digits, marks stay with their base.
+        let chars = part
+            .char_indices()
+            .filter(|(_, c)| c.is_alphanumeric())
+            .collect::<Vec<_>>();
+        let mut start = 0;
+
+        for (i, &(idx, c)) in chars.iter().enumerate().skip(1) {
+            let prev = chars[i - 1].1;
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.1.is_lowercase());
+            if c.is_uppercase() && (!prev.is_uppercase() || next_lower) {
+                words.push(&part[start..idx]);
+                start = idx;
+            }
+        }
+
+        words.push(&part[start..]);
+    }
+
+    words
+}
+
+/// Returns `true` for whitespace, control characters and the characters of the common
+/// punctuation and symbol blocks. Anything not listed, like a combining mark, is kept inside the
+/// word instead of being dropped. The joiners U+200C, U+200D and U+2060 aren't separators either,
+/// as some scripts use them within words.
+fn is_separator(c: char) -> bool {
+    if c.is_ascii() {
+        return !c.is_ascii_alphanumeric();
+    }
+    !c.is_alphanumeric()
+        && (c.is_whitespace()
+            || c.is_control()
+            || matches!(
+                c,
+                '\u{A1}'..='\u{BF}'
+                    | '\u{D7}'
+                    | '\u{F7}'
+                    | '\u{2000}'..='\u{200B}'
+                    | '\u{200E}'..='\u{205F}'
+                    | '\u{2061}'..='\u{206F}'
+                    | '\u{20A0}'..='\u{20CF}'
+                    | '\u{2100}'..='\u{2BFF}'
+                    | '\u{2E00}'..='\u{2E7F}'
+                    | '\u{3000}'..='\u{3029}'
+                    | '\u{3030}'..='\u{303F}'
+                    | '\u{FE10}'..='\u{FE19}'
+                    | '\u{FE30}'..='\u{FE6F}'
+                    | '\u{FF01}'..='\u{FF0F}'
+                    | '\u{FF1A}'..='\u{FF20}'
+                    | '\u{FF3B}'..='\u{FF40}'
+                    | '\u{FF5B}'..='\u{FF65}'
+            ))
+}
+
+/// How a single word gets cased.
+#[derive(Clone, Copy)]
+pub(crate) enum WordCase {
+    Lower,
+    Upper,
+    Capital,
+}
+
+impl WordCase {
+    pub(crate) fn push(self, word: &str, out: &mut String) {
+        match self {
+            WordCase::Lower => out.push_str(&word.to_lowercase()),
+            WordCase::Upper => out.push_str(&word.to_uppercase()),
+            WordCase::Capital => {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    push_titlecase(first, out);
+                    out.push_str(&chars.as_str().to_lowercase());
+                }
+            }
+        }
+    }
+}
+
+/// Pushes the titlecase mapping of `c`. It only differs from `char::to_uppercase` for digraphs,
+/// ligatures, Georgian letters and Greek letters with iota subscript.
+fn push_titlecase(c: char, out: &mut String) {
+    let title = match c {
+        'ß' => "Ss",
+        '\u{1C4}'..='\u{1C6}' => "\u{1C5}",
+        '\u{1C7}'..='\u{1C9}' => "\u{1C8}",
+        '\u{1CA}'..='\u{1CC}' => "\u{1CB}",
+        '\u{1F1}'..='\u{1F3}' => "\u{1F2}",
+        '\u{587}' => "\u{535}\u{582}",
+        // Mkhedruli letters are their own titlecase, only their uppercase is Mtavruli.
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => {
+            out.push(c);
+            return;
+        }
+        // The titlecase letters are the ones with bit 3 set, e.g. U+1F80 maps to U+1F88.
+        '\u{1F80}'..='\u{1FAF}' => {
+            out.extend(char::from_u32(c as u32 | 0x8));
+            return;
+        }
+        '\u{1FB3}' | '\u{1FBC}' => "\u{1FBC}",
+        '\u{1FC3}' | '\u{1FCC}' => "\u{1FCC}",
+        '\u{1FF3}' | '\u{1FFC}' => "\u{1FFC}",
+        '\u{1FB2}' => "\u{1FBA}\u{345}",
+        '\u{1FB4}' => "\u{386}\u{345}",
+        '\u{1FB7}' => "\u{391}\u{342}\u{345}",
+        '\u{1FC2}' => "\u{1FCA}\u{345}",
+        '\u{1FC4}' => "\u{389}\u{345}",
+        '\u{1FC7}' => "\u{397}\u{342}\u{345}",
+        '\u{1FF2}' => "\u{1FFA}\u{345}",
+        '\u{1FF4}' => "\u{38F}\u{345}",
+        '\u{1FF7}' => "\u{3A9}\u{342}\u{345}",
+        '\u{FB00}' => "Ff",
+        '\u{FB01}' => "Fi",
+        '\u{FB02}' => "Fl",
+        '\u{FB03}' => "Ffi",
+        '\u{FB04}' => "Ffl",
+        '\u{FB05}' | '\u{FB06}' => "St",
+        '\u{FB13}' => "\u{544}\u{576}",
+        '\u{FB14}' => "\u{544}\u{565}",
+        '\u{FB15}' => "\u{544}\u{56B}",
+        '\u{FB16}' => "\u{54E}\u{576}",
+        '\u{FB17}' => "\u{544}\u{56D}",
+        _ => {
+            out.extend(c.to_uppercase());
+            return;
+        }
+    };
+    out.push_str(title);
+}
+
+/// Joins the [`words`] of `s` with `sep`, casing the first word with `first` and all others with
+/// `rest`.
+pub(crate) fn convert(s: &str, sep: &str, first: WordCase, rest: WordCase) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (i, word) in words(s).into_iter().enumerate() {
+        if i == 0 {
+            first.push(word, &mut out);
+        } else {
+            out.push_str(sep);
+            rest.push(word, &mut out);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stringtools::StrExt;
+    use test_case::test_case;
+
+    #[test_case("", "", "", ""; "empty")]
+    #[test_case("HTTPServer", "http_server", "httpServer", "Http Server"; "acronym")]
+    #[test_case("getHTTPResponseCode", "get_http_response_code", "getHttpResponseCode", "Get Http Response Code"; "acronym inside")]
+    #[test_case("IOError", "io_error", "ioError", "Io Error"; "short acronym")]
+    #[test_case("fooBar_baz-qux quux", "foo_bar_baz_qux_quux", "fooBarBazQuxQuux", "Foo Bar Baz Qux Quux"; "mixed")]
+    #[test_case("__init__", "init", "init", "Init"; "surrounded")]
+    #[test_case("Base64Encoder", "base64_encoder", "base64Encoder", "Base64 Encoder"; "digits")]
+    #[test_case("area51Zone v2", "area51_zone_v2", "area51ZoneV2", "Area51 Zone V2"; "more digits")]
+    #[test_case("SCREAMING_SNAKE", "screaming_snake", "screamingSnake", "Screaming Snake"; "screaming")]
+    #[test_case("straßeÜber", "straße_über", "straßeÜber", "Straße Über"; "umlauts")]
+    #[test_case("ΟΔΟΣ_ΜΕΓΑΛΟΣ", "οδος_μεγαλος", "οδοςΜεγαλος", "Οδος Μεγαλος"; "final sigma")]
+    #[test_case("nai\u{308}ve_string", "nai\u{308}ve_string", "nai\u{308}veString", "Nai\u{308}ve String"; "nfd")]
+    #[test_case("Cafe\u{301}Name", "cafe\u{301}_name", "cafe\u{301}Name", "Cafe\u{301} Name"; "nfd before boundary")]
+    #[test_case("\u{E01}\u{E48}_x", "\u{E01}\u{E48}_x", "\u{E01}\u{E48}X", "\u{E01}\u{E48} X"; "thai tone mark")]
+    #[test_case("\u{915}\u{93C}\u{93F}_x", "\u{915}\u{93C}\u{93F}_x", "\u{915}\u{93C}\u{93F}X", "\u{915}\u{93C}\u{93F} X"; "devanagari nukta")]
+    #[test_case("price\u{20AC}total\u{2014}sum", "price_total_sum", "priceTotalSum", "Price Total Sum"; "unicode punctuation")]
+    #[test_case("E\u{301}COLE_NAME", "e\u{301}cole_name", "e\u{301}coleName", "E\u{301}cole Name"; "nfd uppercase")]
+    fn case(input: &str, snake: &str, camel: &str, title: &str) {
+        assert_eq!(input.to_snake_case(), snake);
+        assert_eq!(input.to_kebab_case(), snake.replace('_', "-"));
+        assert_eq!(input.to_screaming_snake_case(), snake.to_uppercase());
+        assert_eq!(input.to_camel_case(), camel);
+        assert_eq!(input.to_pascal_case(), title.replace(' ', ""));
+        assert_eq!(input.to_title_case(), title);
+
+        // Converting again doesn't change the words.
+        assert_eq!(camel.to_snake_case(), snake);
+        assert_eq!(title.to_snake_case(), snake);
+    }
+
+    #[test_case("straße", "STRASSE", "Straße"; "sharp s")]
+    #[test_case("ßig", "SSIG", "Ssig"; "leading sharp s")]
+    #[test_case("ﬁle", "FILE", "File"; "ligature")]
+    #[test_case("ǆemal", "ǄEMAL", "ǅemal"; "digraph")]
+    #[test_case("ᾳδη", "ΑΙΔΗ", "ᾼδη"; "iota subscript")]
+    #[test_case("ქართული", "ᲥᲐᲠᲗᲣᲚᲘ", "ქართული"; "georgian")]
+    fn titlecase(word: &str, upper: &str, title: &str) {
+        assert_eq!(word.to_screaming_snake_case(), upper);
+        assert_eq!(word.to_pascal_case(), title);
+    }
+}
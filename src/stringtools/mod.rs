@@ -1,5 +1,47 @@
+mod case;
+mod title;
+
+pub use case::words;
+pub use title::Headline;
+
+use case::{convert, WordCase};
+
 pub trait StrExt {
     fn first_letter_upper(&self) -> String;
+
+    /// Converts the [`words`] of the string to `snake_case`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use utilsrs::stringtools::StrExt;
+    /// assert_eq!("HTTPServer".to_snake_case(), "http_server");
+    /// assert_eq!("HTTPServer".to_kebab_case(), "http-server");
+    /// assert_eq!("HTTPServer".to_camel_case(), "httpServer");
+    /// assert_eq!("http_server".to_pascal_case(), "HttpServer");
+    /// assert_eq!("httpServer".to_screaming_snake_case(), "HTTP_SERVER");
+    /// assert_eq!("http-server".to_title_case(), "Http Server");
+    /// ```
+    fn to_snake_case(&self) -> String;
+
+    /// Converts the [`words`] of the string to `kebab-case`.
+    fn to_kebab_case(&self) -> String;
+
+    /// Converts the [`words`] of the string to `camelCase`.
+    fn to_camel_case(&self) -> String;
+
+    /// Converts the [`words`] of the string to `PascalCase`.
+    fn to_pascal_case(&self) -> String;
+
+    /// Converts the [`words`] of the string to `SCREAMING_SNAKE_CASE`.
+    fn to_screaming_snake_case(&self) -> String;
+
+    /// Converts the [`words`] of the string to `Title Case`, capitalizing every word.
+    fn to_title_case(&self) -> String;
+
+    /// Returns a [`Headline`] to title case the string as a headline. Unlike
+    /// [`to_title_case`](StrExt::to_title_case) this keeps punctuation and small words.
+    fn headline(&self) -> Headline<'_>;
 }
 
 impl<T: AsRef<str>> StrExt for T {
@@ -12,4 +54,39 @@ impl<T: AsRef<str>> StrExt for T {
             Some(f) => f.to_uppercase().chain(c).collect(),
         }
     }
+
+    #[inline]
+    fn to_snake_case(&self) -> String {
+        convert(self.as_ref(), "_", WordCase::Lower, WordCase::Lower)
+    }
+
+    #[inline]
+    fn to_kebab_case(&self) -> String {
+        convert(self.as_ref(), "-", WordCase::Lower, WordCase::Lower)
+    }
+
+    #[inline]
+    fn to_camel_case(&self) -> String {
+        convert(self.as_ref(), "", WordCase::Lower, WordCase::Capital)
+    }
+
+    #[inline]
+    fn to_pascal_case(&self) -> String {
+        convert(self.as_ref(), "", WordCase::Capital, WordCase::Capital)
+    }
+
+    #[inline]
+    fn to_screaming_snake_case(&self) -> String {
+        convert(self.as_ref(), "_", WordCase::Upper, WordCase::Upper)
+    }
+
+    #[inline]
+    fn to_title_case(&self) -> String {
+        convert(self.as_ref(), " ", WordCase::Capital, WordCase::Capital)
+    }
+
+    #[inline]
+    fn headline(&self) -> Headline<'_> {
+        Headline::new(self.as_ref())
+    }
 }
@@ -0,0 +1,290 @@
+use super::case::WordCase;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+/// Articles, conjunctions and short prepositions which stay lowercase by default.
+const SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "per", "the",
+    "to", "up", "via", "vs",
+];
+
+/// Abbreviations whose period doesn't end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "co", "dr", "e.g", "etc", "fig", "i.e", "inc", "jr", "ltd", "mr", "mrs", "ms", "no", "prof",
+    "sr", "st", "vol",
+];
+
+/// Endings of contractions, which aren't capitalized after an apostrophe.
+const CONTRACTIONS: &[&str] = &["d", "ll", "m", "re", "s", "t", "ve"];
+
+/// Title cases a headline, created by [`StrExt::headline`](super::StrExt::headline).
+///
+/// Every word gets capitalized except for small words like "of" or "and", which stay lowercase
+/// unless they start or end the headline or follow a colon or sentence end. Words with
+/// intentional casing like "NASA", "iPhone" or the "A" in "Plan A" are kept as they are, unless
+/// the whole headline is uppercase. Each part of hyphenated words is capitalized and names like
+/// "O'Neill" are recognized, while contractions like "don't" aren't affected. Whitespace and
+/// punctuation are kept as they are.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::stringtools::StrExt;
+/// assert_eq!(
+///     "the state-of-the-art NASA rover: a look inside".headline().to_string(),
+///     "The State-of-the-Art NASA Rover: A Look Inside"
+/// );
+/// assert_eq!(
+///     "news from github".headline().small_word("from").exception("GitHub").build(),
+///     "News from GitHub"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Headline<'a> {
+    text: &'a str,
+    small_words: HashSet<String>,
+    exceptions: HashMap<String, String>,
+    keep_acronyms: bool,
+    hyphenated: bool,
+}
+
+impl<'a> Headline<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            small_words: SMALL_WORDS.iter().map(|w| w.to_string()).collect(),
+            exceptions: HashMap::new(),
+            keep_acronyms: true,
+            hyphenated: true,
+        }
+    }
+
+    /// Adds a word which stays lowercase.
+    pub fn small_word(mut self, word: &str) -> Self {
+        self.small_words.insert(word.to_lowercase());
+        self
+    }
+
+    /// Replaces the default list of words which stay lowercase.
+    pub fn small_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.small_words = words
+            .into_iter()
+            .map(|w| w.as_ref().to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Adds a word which is always written exactly like `word`, regardless of its casing in the
+    /// headline and its position.
+    pub fn exception(mut self, word: &str) -> Self {
+        self.exceptions
+            .insert(word.to_lowercase(), word.to_string());
+        self
+    }
+
+    /// Whether words with intentional casing like "NASA" or "iPhone" are kept. Defaults to `true`.
+    pub fn keep_acronyms(mut self, keep: bool) -> Self {
+        self.keep_acronyms = keep;
+        self
+    }
+
+    /// Whether every part of a hyphenated word is capitalized or only the first one. Defaults to
+    /// `true`.
+    pub fn capitalize_hyphenated(mut self, capitalize: bool) -> Self {
+        self.hyphenated = capitalize;
+        self
+    }
+
+    /// Returns the title cased headline.
+    pub fn build(&self) -> String {
+        // An uppercase headline has no intentional casing.
+        let keep_acronyms = self.keep_acronyms && self.text.chars().any(char::is_lowercase);
+        let count = self.text.split_whitespace().count();
+
+        let mut out = String::with_capacity(self.text.len());
+        let mut rest = self.text;
+        let mut index = 0;
+        let mut starts_phrase = true;
+
+        loop {
+            let start = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            if rest.is_empty() {
+                break;
+            }
+
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let word = &rest[..end];
+            rest = &rest[end..];
+
+            let edge = starts_phrase || index + 1 == count;
+            self.push_word(word, edge, keep_acronyms, &mut out);
+            starts_phrase = self.ends_phrase(word);
+            index += 1;
+        }
+
+        out
+    }
+
+    /// Returns `true` if `word` ends with a colon or ends a sentence. A period after a small word or
+    /// an abbreviation like "vs." or "Dr." doesn't end the sentence.
+    fn ends_phrase(&self, word: &str) -> bool {
+        if word.ends_with([':', '?', '!']) {
+            return true;
+        }
+        if !word.ends_with('.') {
+            return false;
+        }
+        let core = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        !self.small_words.contains(&core) && !ABBREVIATIONS.contains(&core.as_str())
+    }
+
+    /// Pushes `word` with its surrounding punctuation. `edge` is set if small words have to be
+    /// capitalized.
+    fn push_word(&self, word: &str, edge: bool, keep_acronyms: bool, out: &mut String) {
+        let start = word.find(char::is_alphanumeric);
+        let end = word
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8());
+
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) => (start, end),
+            _ => {
+                out.push_str(word);
+                return;
+            }
+        };
+
+        out.push_str(&word[..start]);
+        let core = &word[start..end];
+
+        if let Some(exception) = self.exceptions.get(&core.to_lowercase()) {
+            out.push_str(exception);
+        } else {
+            let parts = core.split('-').count();
+            for (i, part) in core.split('-').enumerate() {
+                if i > 0 {
+                    out.push('-');
+                }
+
+                let lower = part.to_lowercase();
+                let small = self.small_words.contains(&lower) && (i > 0 || (parts == 1 && !edge));
+
+                if let Some(exception) = self.exceptions.get(&lower) {
+                    out.push_str(exception);
+                } else if keep_acronyms && has_intentional_casing(part) {
+                    out.push_str(part);
+                } else if small || (i > 0 && !self.hyphenated) {
+                    out.push_str(&lower);
+                } else {
+                    capitalize(part, out);
+                }
+            }
+        }
+
+        out.push_str(&word[end..]);
+    }
+}
+
+impl Display for Headline<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.build())
+    }
+}
+
+/// Returns `true` for words like "iPhone" with an uppercase letter after the first one, and for a
+/// single uppercase letter like in "Plan A".
+fn has_intentional_casing(word: &str) -> bool {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c.is_uppercase(),
+        _ => word.chars().skip(1).any(char::is_uppercase),
+    }
+}
+
+/// Pushes `word` with its first letter uppercase and all others lowercase. A single letter before
+/// an apostrophe is treated as prefix of a name, so both parts of "O'Neill" are capitalized.
+fn capitalize(word: &str, out: &mut String) {
+    if let Some(pos) = word.find(['\'', '’']) {
+        let (head, tail) = word.split_at(pos);
+        let mut tail = tail.chars();
+        let apostrophe = tail.next().unwrap_or_default();
+        let tail = tail.as_str();
+
+        let is_name = head.chars().count() == 1
+            && tail.chars().count() > 1
+            && !CONTRACTIONS.contains(&tail.to_lowercase().as_str());
+        if is_name {
+            WordCase::Capital.push(head, out);
+            out.push(apostrophe);
+            WordCase::Capital.push(tail, out);
+            return;
+        }
+    }
+
+    WordCase::Capital.push(word, out);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stringtools::StrExt;
+    use test_case::test_case;
+
+    #[test_case("", ""; "empty")]
+    #[test_case("the lord of the rings", "The Lord of the Rings"; "small words")]
+    #[test_case("what are you waiting for", "What Are You Waiting For"; "small last word")]
+    #[test_case("THE LORD OF THE RINGS", "The Lord of the Rings"; "uppercase")]
+    #[test_case("NASA launches new rover to mars", "NASA Launches New Rover to Mars"; "acronym")]
+    #[test_case("the iPhone and the OR ward", "The iPhone and the OR Ward"; "intentional casing")]
+    #[test_case("o'neill's state-of-the-art plan", "O'Neill's State-of-the-Art Plan"; "name and hyphen")]
+    #[test_case("don't stop believin' in it", "Don't Stop Believin' in It"; "contractions")]
+    #[test_case("plan A for success", "Plan A for Success"; "single uppercase letter")]
+    #[test_case("PLAN A FOR SUCCESS", "Plan a for Success"; "single letter in uppercase")]
+    #[test_case("star wars: the empire strikes back", "Star Wars: The Empire Strikes Back"; "colon")]
+    #[test_case("it ended. the sequel begins", "It Ended. The Sequel Begins"; "sentence end")]
+    #[test_case("cats vs. the dogs", "Cats vs. the Dogs"; "small word with period")]
+    #[test_case("dr. no and the spy", "Dr. No and the Spy"; "abbreviation")]
+    #[test_case("  the \"end\"  (of days)  ", "  The \"End\"  (of Days)  "; "punctuation")]
+    #[test_case("in-depth look at école d'été", "In-Depth Look at École D'Été"; "unicode")]
+    fn headline(input: &str, exp: &str) {
+        assert_eq!(input.headline().build(), exp);
+    }
+
+    #[test]
+    fn options() {
+        let s = "self-driving cars in the city";
+        assert_eq!(
+            s.headline().capitalize_hyphenated(false).to_string(),
+            "Self-driving Cars in the City"
+        );
+        assert_eq!(
+            s.headline().small_words(["in"]).to_string(),
+            "Self-Driving Cars in The City"
+        );
+        assert_eq!(
+            "NASA rover".headline().keep_acronyms(false).to_string(),
+            "Nasa Rover"
+        );
+        assert_eq!(
+            "the GITHUB and gitlab story"
+                .headline()
+                .exception("GitHub")
+                .exception("GitLab")
+                .to_string(),
+            "The GitHub and GitLab Story"
+        );
+    }
+}
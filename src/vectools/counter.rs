@@ -0,0 +1,301 @@
+use super::top_k_by_key;
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+    iter::FromIterator,
+    ops::{Add, AddAssign, BitAnd, BitOr, Sub, SubAssign},
+};
+
+/// Counts how often each item occurs, making it a multiset. Items whose count drops to zero are
+/// removed, so two counters are equal if they hold the same items equally often.
+///
+/// The operators combine counters like multisets: `+` adds the counts, `-` subtracts them and
+/// drops items which aren't left over, `&` keeps the minimum count of each item (intersection)
+/// and `|` the maximum (union).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::Counter;
+/// let a = "abracadabra".chars().collect::<Counter<_>>();
+/// assert_eq!(a.get(&'a'), 5);
+/// assert_eq!(a.most_common(1), vec![(&'a', 5)]);
+///
+/// let b = "banana".chars().collect::<Counter<_>>();
+/// let common = a.clone() & b.clone();
+/// assert_eq!(common.get(&'a'), 3);
+/// assert_eq!(common.get(&'n'), 0);
+/// assert_eq!((a.clone() | b.clone()).get(&'n'), 2);
+/// assert_eq!((a + b).total(), 17);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty counter with space for `capacity` distinct items.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            counts: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Counts `item` once and returns its new count.
+    #[inline]
+    pub fn insert(&mut self, item: T) -> usize {
+        self.insert_n(item, 1)
+    }
+
+    /// Counts `item` `n` times and returns its new count.
+    pub fn insert_n(&mut self, item: T, n: usize) -> usize {
+        if n == 0 {
+            return self.get(&item);
+        }
+        let count = self.counts.entry(item).or_default();
+        *count += n;
+        *count
+    }
+
+    /// Removes one occurrence of `item` and returns its remaining count.
+    #[inline]
+    pub fn remove(&mut self, item: &T) -> usize {
+        self.remove_n(item, 1)
+    }
+
+    /// Removes up to `n` occurrences of `item` and returns its remaining count.
+    pub fn remove_n(&mut self, item: &T, n: usize) -> usize {
+        match self.counts.get_mut(item) {
+            Some(count) if *count > n => {
+                *count -= n;
+                *count
+            }
+            Some(_) => {
+                self.counts.remove(item);
+                0
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns how often `item` was counted.
+    #[inline]
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.counts.contains_key(item)
+    }
+
+    /// Returns the amount of distinct items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the sum of all counts.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.counts.clear();
+    }
+
+    /// Returns the `n` most common items with their counts, most common first. Items with the same
+    /// count are returned in unspecified order.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        top_k_by_key(self.iter(), n, |(_, count)| *count)
+    }
+
+    /// Returns `true` if every item occurs at most as often as in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len()
+            && self
+                .counts
+                .iter()
+                .all(|(item, count)| *count <= other.get(item))
+    }
+
+    /// Returns an iterator over the distinct items and their counts in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, count)| (item, *count))
+    }
+
+    /// Returns an iterator over all items, repeating each item as often as it was counted.
+    #[inline]
+    pub fn elements(&self) -> impl Iterator<Item = &T> {
+        self.counts
+            .iter()
+            .flat_map(|(item, count)| std::iter::repeat_n(item, *count))
+    }
+
+    /// Returns the underlying map from items to counts.
+    #[inline]
+    pub fn into_map(self) -> HashMap<T, usize> {
+        self.counts
+    }
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = hash_map::IntoIter<T, usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<T: Hash + Eq> AddAssign for Counter<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (item, count) in rhs {
+            self.insert_n(item, count);
+        }
+    }
+}
+
+impl<T: Hash + Eq> Add for Counter<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Hash + Eq> SubAssign for Counter<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (item, count) in rhs {
+            self.remove_n(&item, count);
+        }
+    }
+}
+
+impl<T: Hash + Eq> Sub for Counter<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Hash + Eq> BitAnd for Counter<T> {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: Self) -> Self {
+        self.counts.retain(|item, count| {
+            *count = (*count).min(rhs.get(item));
+            *count > 0
+        });
+        self
+    }
+}
+
+impl<T: Hash + Eq> BitOr for Counter<T> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self {
+        for (item, count) in rhs {
+            let own = self.counts.entry(item).or_default();
+            *own = (*own).max(count);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn counter(s: &str) -> Counter<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn counting() {
+        let mut c = counter("hello");
+        assert_eq!(c.get(&'l'), 2);
+        assert_eq!(c.get(&'x'), 0);
+        assert_eq!((c.len(), c.total()), (4, 5));
+
+        assert_eq!(c.insert('x'), 1);
+        assert_eq!(c.remove(&'l'), 1);
+        assert_eq!(c.remove_n(&'l', 5), 0);
+        assert!(!c.contains(&'l'));
+        assert_eq!(c, counter("heox"));
+
+        let mut elements = c.elements().collect::<Vec<_>>();
+        elements.sort_unstable();
+        assert_eq!(elements, vec![&'e', &'h', &'o', &'x']);
+    }
+
+    #[test]
+    fn most_common() {
+        let c = counter("mississippi");
+        let top = c.most_common(3);
+        assert_eq!(top.iter().map(|i| i.1).collect::<Vec<_>>(), vec![4, 4, 2]);
+        assert_eq!(top[2], (&'p', 2));
+        assert_eq!(c.most_common(10).len(), 4);
+        assert!(c.most_common(0).is_empty());
+    }
+
+    #[test_case("aab", "abc", "aaabbc", "a", "ab", "aabc"; "overlap")]
+    #[test_case("abc", "", "abc", "abc", "", "abc"; "empty rhs")]
+    #[test_case("aa", "aaa", "aaaaa", "", "aa", "aaa"; "more in rhs")]
+    fn multiset_ops(a: &str, b: &str, sum: &str, diff: &str, and: &str, or: &str) {
+        assert_eq!(counter(a) + counter(b), counter(sum));
+        assert_eq!(counter(a) - counter(b), counter(diff));
+        assert_eq!(counter(a) & counter(b), counter(and));
+        assert_eq!(counter(a) | counter(b), counter(or));
+        assert!(counter(and).is_subset(&counter(a)));
+        assert!(counter(a).is_subset(&counter(or)));
+    }
+}
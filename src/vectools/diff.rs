@@ -0,0 +1,406 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Write},
+};
+
+/// A run of edits turning one slice into another, as returned by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<'a, T> {
+    /// Keep the next `n` elements of the old slice.
+    Equal(usize),
+    /// Remove the next `n` elements of the old slice.
+    Delete(usize),
+    /// Insert these elements of the new slice.
+    Insert(&'a [T]),
+}
+
+/// Computes the shortest edit script turning `old` into `new` using Myers' algorithm in
+/// O((n + m)·d) time, where `d` is the number of inserted and deleted elements. Consecutive
+/// edits of the same kind are combined into runs.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{diff, patch, Edit};
+/// let old = ["a", "b", "c", "d"];
+/// let new = ["a", "c", "x", "d"];
+/// let edits = diff(&old, &new);
+/// assert_eq!(
+///     edits,
+///     vec![Edit::Equal(1), Edit::Delete(1), Edit::Equal(1), Edit::Insert(&["x"]), Edit::Equal(1)]
+/// );
+/// assert_eq!(patch(&old, &edits).unwrap(), new);
+/// ```
+pub fn diff<'a, T: PartialEq>(old: &[T], new: &'a [T]) -> Vec<Edit<'a, T>> {
+    let mut edits: Vec<Edit<'a, T>> = Vec::new();
+    let mut new_pos = 0;
+
+    for op in myers(old, new) {
+        match (op, edits.last_mut()) {
+            (Op::Equal, Some(Edit::Equal(n))) | (Op::Delete, Some(Edit::Delete(n))) => *n += 1,
+            (Op::Insert, Some(Edit::Insert(items))) => {
+                *items = &new[new_pos - items.len()..=new_pos];
+            }
+            (Op::Equal, _) => edits.push(Edit::Equal(1)),
+            (Op::Delete, _) => edits.push(Edit::Delete(1)),
+            (Op::Insert, _) => edits.push(Edit::Insert(&new[new_pos..=new_pos])),
+        }
+        if op != Op::Delete {
+            new_pos += 1;
+        }
+    }
+
+    edits
+}
+
+/// Error returned by [`patch`] if the edit script doesn't fit the slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchError {
+    /// The edits keep or delete more elements than the slice has.
+    SourceTooShort,
+    /// The edits don't cover all elements of the slice.
+    SourceTooLong,
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::SourceTooShort => write!(f, "edit script exceeds the source"),
+            PatchError::SourceTooLong => write!(f, "edit script doesn't cover the whole source"),
+        }
+    }
+}
+
+impl Error for PatchError {}
+
+/// Applies the `edits` returned by [`diff`] to `old`.
+pub fn patch<T: Clone>(old: &[T], edits: &[Edit<T>]) -> Result<Vec<T>, PatchError> {
+    let mut out = Vec::with_capacity(old.len());
+    let mut pos = 0;
+
+    for edit in edits {
+        match edit {
+            Edit::Equal(n) | Edit::Delete(n) => {
+                let end = pos + n;
+                let items = old.get(pos..end).ok_or(PatchError::SourceTooShort)?;
+                if let Edit::Equal(_) = edit {
+                    out.extend_from_slice(items);
+                }
+                pos = end;
+            }
+            Edit::Insert(items) => out.extend_from_slice(items),
+        }
+    }
+
+    if pos < old.len() {
+        return Err(PatchError::SourceTooLong);
+    }
+
+    Ok(out)
+}
+
+/// Renders the differences between the lines `old` and `new` in the unified diff format, with
+/// `context` unchanged lines around each change. Returns an empty string if both are equal.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::unified_diff;
+/// let old = "a\nb\nc\nd\ne".lines().collect::<Vec<_>>();
+/// let new = "a\nb\nC\nd\ne".lines().collect::<Vec<_>>();
+/// assert_eq!(unified_diff(&old, &new, 1), "@@ -2,3 +2,3 @@\n b\n-c\n+C\n d\n");
+/// ```
+pub fn unified_diff(old: &[&str], new: &[&str], context: usize) -> String {
+    // Every line of the diff with its tag and the position in the old and new slice.
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for op in myers(old, new) {
+        lines.push((op, old_pos, new_pos));
+        match op {
+            Op::Equal => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Op::Delete => old_pos += 1,
+            Op::Insert => new_pos += 1,
+        }
+    }
+
+    let changes = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.0 != Op::Equal)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    // Group the changes into hunks, merging hunks whose context would overlap.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for i in changes {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        let old_count = hunk.iter().filter(|l| l.0 != Op::Insert).count();
+        let new_count = hunk.iter().filter(|l| l.0 != Op::Delete).count();
+
+        writeln!(
+            out,
+            "@@ -{} +{} @@",
+            hunk_range(hunk[0].1, old_count),
+            hunk_range(hunk[0].2, new_count)
+        )
+        .unwrap();
+
+        for &(op, old_pos, new_pos) in hunk {
+            let (prefix, line) = match op {
+                Op::Equal => (' ', old[old_pos]),
+                Op::Delete => ('-', old[old_pos]),
+                Op::Insert => ('+', new[new_pos]),
+            };
+            writeln!(out, "{}{}", prefix, line).unwrap();
+        }
+    }
+
+    out
+}
+
+/// Formats a hunk range like GNU diff: the count is omitted if it's one and an empty range starts
+/// at the line before it.
+fn hunk_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Returns the shortest edit script from `a` to `b` as single element operations. Uses the linear
+/// space variant of Myers' algorithm, which recursively splits both slices at the middle of an
+/// optimal path, so memory stays O(n + m) while the time is still O((n + m)·d).
+pub(super) fn myers<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Op> {
+    let max = (a.len() + b.len()).div_ceil(2);
+    // Diagonals `-d - 1..=d + 1` are accessed for all `d <= max`.
+    let mut forward = vec![0; 2 * max + 3];
+    let mut backward = vec![0; 2 * max + 3];
+    let mut ops = Vec::with_capacity(a.len() + b.len());
+    myers_rec(a, b, &mut forward, &mut backward, &mut ops);
+    ops
+}
+
+fn myers_rec<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    forward: &mut [usize],
+    backward: &mut [usize],
+    ops: &mut Vec<Op>,
+) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    ops.extend(std::iter::repeat_n(Op::Equal, prefix));
+    if a.is_empty() {
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+    } else if b.is_empty() {
+        ops.extend(std::iter::repeat_n(Op::Delete, a.len()));
+    } else {
+        let (x, y) = middle_snake(a, b, forward, backward);
+        myers_rec(&a[..x], &b[..y], forward, backward, ops);
+        myers_rec(&a[x..], &b[y..], forward, backward, ops);
+    }
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+}
+
+/// Returns a point on a shortest edit path from `a` to `b` which splits it into two halves with
+/// about the same amount of edits. Both slices must be non empty and differ in their first and
+/// last elements, so the path has at least two edits and neither half is the whole path.
+fn middle_snake<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    forward: &mut [usize],
+    backward: &mut [usize],
+) -> (usize, usize) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let offset = forward.len() as isize / 2;
+    let idx = |k: isize| (k + offset) as usize;
+
+    // `forward[k]` is the furthest x reached on diagonal `k = x - y` from the start, `backward[k]`
+    // the furthest x reached on diagonal `k` from the end, counted backwards.
+    forward[idx(1)] = 0;
+    backward[idx(1)] = 0;
+
+    for d in 0..=(n + m + 1) / 2 {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[idx(k - 1)] < forward[idx(k + 1)]) {
+                forward[idx(k + 1)] as isize
+            } else {
+                forward[idx(k - 1)] as isize + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[idx(k)] = x as usize;
+
+            // An odd delta means both paths meet after the forward step.
+            let back_k = delta - k;
+            if delta % 2 != 0 && back_k.abs() < d && x + backward[idx(back_k)] as isize >= n {
+                return (x as usize, y as usize);
+            }
+        }
+
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && backward[idx(k - 1)] < backward[idx(k + 1)]) {
+                backward[idx(k + 1)] as isize
+            } else {
+                backward[idx(k - 1)] as isize + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[(n - 1 - x) as usize] == b[(m - 1 - y) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[idx(k)] = x as usize;
+
+            let forward_k = delta - k;
+            if delta % 2 == 0 && forward_k.abs() <= d && x + forward[idx(forward_k)] as isize >= n {
+                return ((n - x) as usize, (m - y) as usize);
+            }
+        }
+    }
+
+    unreachable!("paths always meet after (n + m) / 2 rounds")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", ""; "empty")]
+    #[test_case("", "abc"; "insert only")]
+    #[test_case("abc", ""; "delete only")]
+    #[test_case("abcabba", "cbabac"; "paper example")]
+    #[test_case("kitten", "sitting"; "substitutions")]
+    #[test_case("same", "same"; "equal")]
+    fn roundtrip(old: &str, new: &str) {
+        let old = old.chars().collect::<Vec<_>>();
+        let new = new.chars().collect::<Vec<_>>();
+        let edits = diff(&old, &new);
+        assert_eq!(patch(&old, &edits).unwrap(), new);
+
+        // Merged runs never follow a run of the same kind.
+        for pair in edits.windows(2) {
+            assert_ne!(
+                std::mem::discriminant(&pair[0]),
+                std::mem::discriminant(&pair[1])
+            );
+        }
+    }
+
+    #[test]
+    fn shortest() {
+        let old = "abcabba".chars().collect::<Vec<_>>();
+        let new = "cbabac".chars().collect::<Vec<_>>();
+        let changes: usize = diff(&old, &new)
+            .iter()
+            .map(|e| match e {
+                Edit::Equal(_) => 0,
+                Edit::Delete(n) => *n,
+                Edit::Insert(i) => i.len(),
+            })
+            .sum();
+        assert_eq!(changes, 5);
+    }
+
+    #[test]
+    fn patch_errors() {
+        assert_eq!(
+            patch(&[1, 2], &[Edit::Equal(3)]),
+            Err(PatchError::SourceTooShort)
+        );
+        assert_eq!(
+            patch(&[1, 2], &[Edit::Delete(1)]),
+            Err(PatchError::SourceTooLong)
+        );
+    }
+
+    #[test]
+    fn unified() {
+        let old = (1..=10).map(|i| i.to_string()).collect::<Vec<_>>();
+        let old = old.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let mut new = old.clone();
+        new.remove(0);
+        new[6] = "x";
+        new.push("11");
+
+        let exp = "\
+@@ -1,3 +1,2 @@
+-1
+ 2
+ 3
+@@ -6,5 +5,6 @@
+ 6
+ 7
+-8
++x
+ 9
+ 10
++11
+";
+        assert_eq!(unified_diff(&old, &new, 2), exp);
+        assert_eq!(unified_diff(&old, &old, 2), "");
+        assert_eq!(unified_diff(&[], &["a"], 3), "@@ -0,0 +1 @@\n+a\n");
+    }
+
+    #[test]
+    fn large_disjoint() {
+        // Would need O((n + m)²) memory if every round of the search was kept.
+        let old = (0..4000).collect::<Vec<_>>();
+        let new = (4000..8000).collect::<Vec<_>>();
+        let edits = diff(&old, &new);
+        assert_eq!(edits, vec![Edit::Delete(4000), Edit::Insert(&new[..])]);
+        assert_eq!(patch(&old, &edits).unwrap(), new);
+    }
+
+    #[test]
+    fn large_interleaved() {
+        let old = (0..6000).filter(|i| i % 3 != 0).collect::<Vec<_>>();
+        let new = (0..6000).filter(|i| i % 5 != 0).collect::<Vec<_>>();
+        let edits = diff(&old, &new);
+        assert_eq!(patch(&old, &edits).unwrap(), new);
+
+        // Elements divisible by 5 but not 3 are deleted, those divisible by 3 but not 5 inserted.
+        let changes: usize = edits
+            .iter()
+            .map(|e| match e {
+                Edit::Equal(_) => 0,
+                Edit::Delete(n) => *n,
+                Edit::Insert(i) => i.len(),
+            })
+            .sum();
+        assert_eq!(changes, 800 + 1600);
+    }
+}
@@ -0,0 +1,364 @@
+use std::ops::Range;
+
+use super::diff::{myers, Op};
+
+/// Returns the Levenshtein distance of `a` and `b`, the minimum number of insertions, deletions
+/// and substitutions turning one into the other. Runs in O(n·m) time and O(min(n, m)) memory.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::levenshtein;
+/// assert_eq!(levenshtein(&[1, 2, 3, 4], &[2, 3, 5, 4, 6]), 3);
+/// ```
+#[inline]
+pub fn levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    edit_distance(a, b, false, usize::MAX).unwrap()
+}
+
+/// Same as [`levenshtein`] but returns `None` as soon as the distance is known to exceed `max`.
+/// Only cells within `max` of the diagonal are computed, so this runs in O(n·max).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::levenshtein_bounded;
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 3), Some(3));
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 2), None);
+/// ```
+#[inline]
+pub fn levenshtein_bounded<T: PartialEq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    edit_distance(a, b, false, max)
+}
+
+/// Returns the Damerau–Levenshtein distance of `a` and `b` in its optimal string alignment
+/// variant: like [`levenshtein`] but swapping two adjacent elements counts as a single edit. A
+/// substring can't be edited again after a transposition.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{damerau_levenshtein, levenshtein};
+/// assert_eq!(damerau_levenshtein(b"recieve", b"receive"), 1);
+/// assert_eq!(levenshtein(b"recieve", b"receive"), 2);
+/// assert_eq!(damerau_levenshtein(b"ca", b"abc"), 3);
+/// ```
+#[inline]
+pub fn damerau_levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    edit_distance(a, b, true, usize::MAX).unwrap()
+}
+
+/// Same as [`damerau_levenshtein`] but returns `None` as soon as the distance is known to exceed
+/// `max`.
+#[inline]
+pub fn damerau_levenshtein_bounded<T: PartialEq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    edit_distance(a, b, true, max)
+}
+
+/// Same as [`levenshtein`] but compares the chars of two strings.
+#[inline]
+pub fn levenshtein_str(a: &str, b: &str) -> usize {
+    levenshtein(&chars(a), &chars(b))
+}
+
+/// Same as [`levenshtein_bounded`] but compares the chars of two strings.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::levenshtein_str_bounded;
+/// assert_eq!(levenshtein_str_bounded("Straße", "Strasse", 2), Some(2));
+/// assert_eq!(levenshtein_str_bounded("Straße", "Stroβe", 1), None);
+/// ```
+#[inline]
+pub fn levenshtein_str_bounded(a: &str, b: &str, max: usize) -> Option<usize> {
+    levenshtein_bounded(&chars(a), &chars(b), max)
+}
+
+/// Same as [`damerau_levenshtein`] but compares the chars of two strings.
+#[inline]
+pub fn damerau_levenshtein_str(a: &str, b: &str) -> usize {
+    damerau_levenshtein(&chars(a), &chars(b))
+}
+
+/// Same as [`damerau_levenshtein_bounded`] but compares the chars of two strings.
+#[inline]
+pub fn damerau_levenshtein_str_bounded(a: &str, b: &str, max: usize) -> Option<usize> {
+    damerau_levenshtein_bounded(&chars(a), &chars(b), max)
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`. Runs in O((n + m)·d),
+/// where `d` is the number of elements not part of the subsequence.
+#[inline]
+pub fn lcs_len<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    myers(a, b)
+        .into_iter()
+        .filter(|op| *op == Op::Equal)
+        .count()
+}
+
+/// Returns a longest common subsequence of `a` and `b` as pairs of indices into `a` and `b`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::lcs_alignment;
+/// let a = ['x', 'a', 'b', 'c'];
+/// let b = ['a', 'y', 'c'];
+/// assert_eq!(lcs_alignment(&a, &b), vec![(1, 0), (3, 2)]);
+/// ```
+pub fn lcs_alignment<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (mut i, mut j) = (0, 0);
+    let mut pairs = Vec::new();
+
+    for op in myers(a, b) {
+        match op {
+            Op::Equal => {
+                pairs.push((i, j));
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+
+    pairs
+}
+
+/// Same as [`lcs_len`] but compares the chars of two strings.
+#[inline]
+pub fn lcs_len_str(a: &str, b: &str) -> usize {
+    lcs_len(&chars(a), &chars(b))
+}
+
+/// Returns the positions of the longest contiguous run which occurs in both `a` and `b`, or `None`
+/// if they have no element in common. Of several runs with the same length the first one in `a`
+/// is returned. Runs in O(n·m) time and O(m) memory.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::longest_common_substring;
+/// let (a, b) = longest_common_substring(&[1, 2, 3, 4, 5], &[9, 3, 4, 5, 1]).unwrap();
+/// assert_eq!((a, b), (2..5, 1..4));
+/// ```
+pub fn longest_common_substring<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+) -> Option<(Range<usize>, Range<usize>)> {
+    // `row[j]` is the length of the common run ending at the current element of `a` and `b[j - 1]`.
+    let mut row = vec![0; b.len() + 1];
+    let mut best = (0, 0, 0);
+
+    for (i, a_val) in a.iter().enumerate() {
+        // Iterate backwards so `row[j - 1]` still holds the value of the previous row.
+        for j in (1..=b.len()).rev() {
+            row[j] = if *a_val == b[j - 1] {
+                row[j - 1] + 1
+            } else {
+                0
+            };
+            if row[j] > best.0 {
+                best = (row[j], i + 1, j);
+            }
+        }
+    }
+
+    let (len, a_end, b_end) = best;
+    (len > 0).then(|| (a_end - len..a_end, b_end - len..b_end))
+}
+
+/// Same as [`longest_common_substring`] but returns the longest common substring of two strings.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::longest_common_substring_str;
+/// assert_eq!(longest_common_substring_str("Grüße aus Köln", "Kölner Grüße"), "Grüße");
+/// assert_eq!(longest_common_substring_str("abc", "xyz"), "");
+/// ```
+pub fn longest_common_substring_str<'a>(a: &'a str, b: &str) -> &'a str {
+    let a_chars = a.char_indices().collect::<Vec<_>>();
+    let a_only = a_chars.iter().map(|c| c.1).collect::<Vec<_>>();
+
+    match longest_common_substring(&a_only, &chars(b)) {
+        Some((range, _)) => {
+            let start = a_chars[range.start].0;
+            let end = a_chars.get(range.end).map_or(a.len(), |c| c.0);
+            &a[start..end]
+        }
+        None => "",
+    }
+}
+
+#[inline]
+fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+/// Computes the (optimal string alignment) edit distance of `a` and `b`, giving up once it exceeds
+/// `max`.
+fn edit_distance<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    transpositions: bool,
+    max: usize,
+) -> Option<usize> {
+    // Rows iterate over the longer slice so the rows are as short as possible.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if a.len() - b.len() > max {
+        return None;
+    }
+
+    // A common prefix or suffix never changes the distance.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    let (n, m) = (a.len(), b.len());
+    if m == 0 {
+        return (n <= max).then_some(n);
+    }
+
+    // The distance is at most `n`, which keeps `inf` from overflowing. Cells further than `max`
+    // away from the diagonal can't be within the bound and are treated as `inf`.
+    let max = max.min(n);
+    let inf = max + 1;
+
+    let mut prev2 = vec![inf; m + 1];
+    let mut prev = (0..=m).map(|j| j.min(inf)).collect::<Vec<_>>();
+    let mut cur = vec![inf; m + 1];
+
+    for i in 1..=n {
+        let lo = i.saturating_sub(max).max(1);
+        let hi = (i + max).min(m);
+        cur[lo - 1] = if lo == 1 { i.min(inf) } else { inf };
+
+        let mut row_min = cur[lo - 1];
+        for j in lo..=hi {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut dist = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1);
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist = dist.min(prev2[j - 2] + 1);
+            }
+            cur[j] = dist.min(inf);
+            row_min = row_min.min(cur[j]);
+        }
+        if hi < m {
+            cur[hi + 1] = inf;
+        }
+
+        if row_min > max {
+            return None;
+        }
+
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    Some(prev[m]).filter(|d| *d <= max)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    /// Textbook full-matrix implementation to compare the optimized one against.
+    fn naive(a: &[u8], b: &[u8], transpositions: bool) -> usize {
+        let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..=a.len() {
+            for j in 0..=b.len() {
+                d[i][j] = if i == 0 || j == 0 {
+                    i + j
+                } else {
+                    let cost = usize::from(a[i - 1] != b[j - 1]);
+                    let mut v = (d[i - 1][j - 1] + cost)
+                        .min(d[i - 1][j] + 1)
+                        .min(d[i][j - 1] + 1);
+                    if transpositions
+                        && i > 1
+                        && j > 1
+                        && a[i - 1] == b[j - 2]
+                        && a[i - 2] == b[j - 1]
+                    {
+                        v = v.min(d[i - 2][j - 2] + 1);
+                    }
+                    v
+                };
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    #[test_case("", "", 0, 0; "empty")]
+    #[test_case("abc", "", 3, 3; "one empty")]
+    #[test_case("kitten", "sitting", 3, 3; "kitten")]
+    #[test_case("ab", "ba", 2, 1; "transposition")]
+    #[test_case("ca", "abc", 3, 3; "osa restriction")]
+    #[test_case("abcdef", "badcfe", 4, 3; "many transpositions")]
+    #[test_case("flaw", "lawn", 2, 2; "shift")]
+    fn distances(a: &str, b: &str, lev: usize, dam: usize) {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        assert_eq!(levenshtein(a, b), lev);
+        assert_eq!(levenshtein(b, a), lev);
+        assert_eq!(damerau_levenshtein(a, b), dam);
+
+        for max in 0..8 {
+            let exp = |d: usize| Some(d).filter(|d| *d <= max);
+            assert_eq!(levenshtein_bounded(a, b, max), exp(lev));
+            assert_eq!(damerau_levenshtein_bounded(a, b, max), exp(dam));
+        }
+    }
+
+    #[test]
+    fn against_naive() {
+        // Small alphabet, so there are many matches and transpositions.
+        let words = (0..300u32)
+            .map(|i| {
+                let len = (i * 7 % 9) as usize;
+                (0..len)
+                    .map(|j| b"abc"[(i as usize * 31 + j * j * 17 + j) % 3])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for pair in words.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            for &t in &[false, true] {
+                let exp = naive(a, b, t);
+                assert_eq!(edit_distance(a, b, t, usize::MAX), Some(exp));
+                assert_eq!(edit_distance(a, b, t, 2), Some(exp).filter(|d| *d <= 2));
+            }
+        }
+    }
+
+    #[test]
+    fn lcs() {
+        let a = b"ABCBDAB";
+        let b = b"BDCABA";
+        assert_eq!(lcs_len(a, b), 4);
+        let pairs = lcs_alignment(a, b);
+        assert_eq!(pairs.len(), 4);
+        assert!(pairs.windows(2).all(|p| p[0].0 < p[1].0 && p[0].1 < p[1].1));
+        assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+        assert_eq!(lcs_len_str("añb", "ab"), 2);
+    }
+
+    #[test]
+    fn common_substring() {
+        assert_eq!(longest_common_substring::<u8>(&[], &[1]), None);
+        assert_eq!(
+            longest_common_substring(b"xabcyabcd", b"abcd"),
+            Some((5..9, 0..4))
+        );
+        assert_eq!(longest_common_substring_str("ßtraße", "Maße"), "aße");
+    }
+}
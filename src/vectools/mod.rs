@@ -1,44 +1,166 @@
-use std::cmp::Ordering;
+mod counter;
+mod diff;
+mod distance;
+mod order;
+mod search;
+mod select;
+mod set;
+mod shape;
+mod sorted;
 
-/// Return true if both slices have the same elments without being stored to be in the same order
+pub use counter::Counter;
+pub use diff::{diff, patch, unified_diff, Edit, PatchError};
+pub use distance::{
+    damerau_levenshtein, damerau_levenshtein_bounded, damerau_levenshtein_str,
+    damerau_levenshtein_str_bounded, lcs_alignment, lcs_len, lcs_len_str, levenshtein,
+    levenshtein_bounded, levenshtein_str, levenshtein_str_bounded, longest_common_substring,
+    longest_common_substring_str,
+};
+pub use order::{
+    sort_by_reference, sort_by_reference_key, topological_sort, CycleError, OrderIndex,
+};
+pub use search::{
+    equal_range, equal_range_by, equal_range_by_key, exponential_search, exponential_search_by,
+    exponential_search_by_key, lower_bound, lower_bound_by, lower_bound_by_key,
+    partition_point_by_key, upper_bound, upper_bound_by, upper_bound_by_key,
+};
+pub use select::{
+    bottom_k, bottom_k_by_key, kth_largest, kth_smallest, kth_smallest_by, kth_smallest_by_key,
+    partial_sort, partial_sort_by, partial_sort_by_key, top_k, top_k_by_key,
+};
+pub use set::{
+    difference, difference_hash, difference_sorted, difference_sorted_by, difference_sorted_by_key,
+    intersection, intersection_hash, intersection_sorted, intersection_sorted_by,
+    intersection_sorted_by_key, is_disjoint, is_disjoint_hash, is_disjoint_sorted,
+    is_disjoint_sorted_by, is_disjoint_sorted_by_key, symmetric_difference,
+    symmetric_difference_hash, symmetric_difference_sorted, symmetric_difference_sorted_by,
+    symmetric_difference_sorted_by_key, union, union_hash, union_sorted, union_sorted_by,
+    union_sorted_by_key, SortedSetIter,
+};
+pub use shape::{flatten, reshape, transpose, unflatten, Fill, ShapeError};
+pub use sorted::{SortedSet, SortedVec};
+
+use set::by_key;
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+/// Return true if both slices have the same elments without being stored to be in the same order.
+/// Uses multiset semantics: each element has to occur equally often in both slices. This runs in
+/// O(n²), prefer [`same_elements_hash`] or [`same_elements_ord`] if `T` allows it.
 #[inline]
 pub fn same_elements<T>(v1: &[T], v2: &[T]) -> bool
 where
     T: PartialEq,
 {
-    if v1.len() != v2.len() {
+    v1.len() == v2.len() && contains_all(v1, v2)
+}
+
+/// Return true if [`v1`] is a subset of [`v2`]. Uses multiset semantics: each element of `v1` has to
+/// occur at least as often in `v2`. An empty `v1` is never part of `v2`. This runs in O(n·m),
+/// prefer [`part_of_hash`] or [`part_of_ord`] if `T` allows it.
+#[inline]
+pub fn part_of<T>(v1: &[T], v2: &[T]) -> bool
+where
+    T: PartialEq,
+{
+    if v1.len() > v2.len() || v1.is_empty() {
         return false;
     }
 
-    for i in v1 {
-        if !v2.contains(&i) {
-            return false;
-        }
+    contains_all(v1, v2)
+}
+
+/// Same as [`same_elements`] but runs in O(n) by comparing the [`Counter`]s of both slices.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::same_elements_hash;
+/// assert!(same_elements_hash(&[1, 2, 1], &[1, 1, 2]));
+/// assert!(!same_elements_hash(&[1, 1, 2], &[1, 2, 2]));
+/// ```
+pub fn same_elements_hash<T>(v1: &[T], v2: &[T]) -> bool
+where
+    T: Hash + Eq,
+{
+    v1.len() == v2.len() && v1.iter().collect::<Counter<_>>() == v2.iter().collect()
+}
+
+/// Same as [`part_of`] but runs in O(n + m) by comparing the [`Counter`]s of both slices.
+pub fn part_of_hash<T>(v1: &[T], v2: &[T]) -> bool
+where
+    T: Hash + Eq,
+{
+    if v1.len() > v2.len() || v1.is_empty() {
+        return false;
     }
 
-    true
+    contains_all_hash(v1, v2)
 }
 
-/// Return true if [`v1`] is a subset of [`v2`]
-#[inline]
-pub fn part_of<T>(v1: &[T], v2: &[T]) -> bool
+/// Same as [`same_elements`] but runs in O(n log n) by comparing sorted references to the
+/// elements.
+pub fn same_elements_ord<T>(v1: &[T], v2: &[T]) -> bool
 where
-    T: PartialEq,
+    T: Ord,
+{
+    v1.len() == v2.len() && sorted_refs(v1) == sorted_refs(v2)
+}
+
+/// Same as [`part_of`] but runs in O(n log n + m log m) by comparing sorted references to the
+/// elements.
+pub fn part_of_ord<T>(v1: &[T], v2: &[T]) -> bool
+where
+    T: Ord,
 {
     if v1.len() > v2.len() || v1.is_empty() {
         return false;
     }
 
-    for i in v1 {
-        if !v2.contains(&i) {
-            return false;
+    is_subset(&sorted_refs(v1), &sorted_refs(v2))
+}
+
+/// Returns `true` if each element of `v1` can be matched with a distinct element of `v2`.
+fn contains_all<T: PartialEq>(v1: &[T], v2: &[T]) -> bool {
+    let mut used = vec![false; v2.len()];
+
+    v1.iter().all(|i| {
+        let pos = v2
+            .iter()
+            .zip(used.iter())
+            .position(|(j, used)| !used && i == j);
+
+        match pos {
+            Some(pos) => {
+                used[pos] = true;
+                true
+            }
+            None => false,
         }
-    }
+    })
+}
 
-    true
+/// Same as [`contains_all`] but counting the elements with a [`Counter`].
+fn contains_all_hash<T: Hash + Eq>(v1: &[T], v2: &[T]) -> bool {
+    v1.iter()
+        .collect::<Counter<_>>()
+        .is_subset(&v2.iter().collect())
 }
 
-/// Returns the cutset of both slices as newly allocated vector
+#[inline]
+fn sorted_refs<T: Ord>(v: &[T]) -> Vec<&T> {
+    let mut refs = v.iter().collect::<Vec<_>>();
+    refs.sort_unstable();
+    refs
+}
+
+/// Returns the cutset of both slices as newly allocated vector. Despite its name this is the
+/// intersection of both slices, not their union.
+#[deprecated(note = "computes the intersection, use `intersection` instead")]
 #[inline]
 pub fn union_elements<'a, T>(v1: &'a [T], v2: &'a [T]) -> Vec<&'a T>
 where
@@ -50,6 +172,7 @@ where
 /// Get the relative order of two elements within a vector.
 /// Requires that a, b being element of vec.
 /// In case there are more elements that would match, the first matching one will be handled.
+/// Use an [`OrderIndex`] for repeated queries on the same vector.
 pub fn get_item_order<T>(vec: &[T], a: &T, b: &T) -> Option<Ordering>
 where
     T: PartialEq,
@@ -90,7 +213,8 @@ where
 }
 
 /// Remove duplicates from a vector and return a newly allocated one. This doesn't need the source
-/// vector to be sorted unlike `.dedup()`. Therefore it's heavier in workload
+/// vector to be sorted unlike `.dedup()`. Therefore it's heavier in workload. Runs in O(n²),
+/// prefer [`remove_dups_hash`] if `T` allows it.
 #[inline]
 pub fn remove_dups<T>(inp: Vec<T>) -> Vec<T>
 where
@@ -99,6 +223,138 @@ where
     remove_dups_by(inp, |a, b| a == b)
 }
 
+/// Same as [`remove_dups`] but runs in O(n) using a `HashSet`. Keeps the first occurrence of each
+/// element and the order of the remaining elements.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::remove_dups_hash;
+/// assert_eq!(remove_dups_hash(vec![3, 1, 3, 2, 1]), vec![3, 1, 2]);
+/// ```
+#[inline]
+pub fn remove_dups_hash<T>(mut inp: Vec<T>) -> Vec<T>
+where
+    T: Hash + Eq,
+{
+    retain_unique(&mut inp);
+    inp
+}
+
+/// Removes all elements whose key returned by `f` has already been seen and returns the remaining
+/// ones in their original order. Runs in O(n).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::remove_dups_by_key;
+/// let users = vec![(1, "alice"), (2, "bob"), (1, "alice2")];
+/// assert_eq!(remove_dups_by_key(users, |u| u.0), vec![(1, "alice"), (2, "bob")]);
+/// ```
+#[inline]
+pub fn remove_dups_by_key<T, K, F>(mut inp: Vec<T>, f: F) -> Vec<T>
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> K,
+{
+    retain_unique_by_key(&mut inp, f);
+    inp
+}
+
+/// Same as [`remove_dups_hash`] but removes the duplicates in place instead of collecting the
+/// unique elements into a new vector. Returns the number of removed elements.
+#[inline]
+pub fn retain_unique<T>(vec: &mut Vec<T>) -> usize
+where
+    T: Hash + Eq,
+{
+    retain_unique_with(vec, &RandomState::new())
+}
+
+/// Moves the unique elements to the front of `vec` and truncates it. The unique elements are
+/// looked up by their hash and index, as a set of references would borrow from `vec`.
+fn retain_unique_with<T, S>(vec: &mut Vec<T>, state: &S) -> usize
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    let len = vec.len();
+    let mut seen = HashMap::<u64, usize>::with_capacity(len);
+    let mut unique = 0;
+
+    for i in 0..len {
+        let hash = state.hash_one(&vec[i]);
+        let is_dup = match seen.get(&hash) {
+            Some(&j) if vec[j] == vec[i] => true,
+            // On a hash collision all unique elements are searched, which is very unlikely.
+            Some(_) => vec[..unique].contains(&vec[i]),
+            None => {
+                seen.insert(hash, unique);
+                false
+            }
+        };
+        if !is_dup {
+            vec.swap(unique, i);
+            unique += 1;
+        }
+    }
+
+    vec.truncate(unique);
+    len - unique
+}
+
+/// Same as [`remove_dups_by_key`] but removes the duplicates in place. Returns the number of
+/// removed elements. Allocates a `HashSet` of the keys of the unique elements.
+pub fn retain_unique_by_key<T, K, F>(vec: &mut Vec<T>, mut f: F) -> usize
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> K,
+{
+    let mut seen = HashSet::with_capacity(vec.len());
+    let len = vec.len();
+    vec.retain(|i| seen.insert(f(i)));
+    len - vec.len()
+}
+
+/// Splits `inp` into its unique elements and the removed duplicates, both in their original
+/// order. Runs in O(n).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::split_dups;
+/// let (unique, dups) = split_dups(vec![1, 2, 1, 3, 2, 1]);
+/// assert_eq!(unique, vec![1, 2, 3]);
+/// assert_eq!(dups, vec![1, 2, 1]);
+/// ```
+pub fn split_dups<T>(inp: Vec<T>) -> (Vec<T>, Vec<T>)
+where
+    T: Hash + Eq,
+{
+    let mut seen = HashSet::with_capacity(inp.len());
+    let keep = inp.iter().map(|i| seen.insert(i)).collect::<Vec<_>>();
+    split_flagged(inp, keep)
+}
+
+/// Same as [`split_dups`] but compares the elements by the key returned by `f`.
+pub fn split_dups_by_key<T, K, F>(inp: Vec<T>, mut f: F) -> (Vec<T>, Vec<T>)
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> K,
+{
+    let mut seen = HashSet::with_capacity(inp.len());
+    let keep = inp.iter().map(|i| seen.insert(f(i))).collect::<Vec<_>>();
+    split_flagged(inp, keep)
+}
+
+fn split_flagged<T>(inp: Vec<T>, keep: Vec<bool>) -> (Vec<T>, Vec<T>) {
+    let (unique, dups): (Vec<_>, Vec<_>) = inp.into_iter().zip(keep).partition(|(_, k)| *k);
+    (
+        unique.into_iter().map(|(i, _)| i).collect(),
+        dups.into_iter().map(|(i, _)| i).collect(),
+    )
+}
+
 #[inline]
 fn contains<T, F>(inp: &[T], item: &T, eq: F) -> bool
 where
@@ -109,41 +365,310 @@ where
 
 // Returns `true` if `a` is a subset of `b`.
 pub fn is_subset<T: PartialOrd>(a: &[T], b: &[T]) -> bool {
+    // Incomparable values are treated as equal.
+    is_subset_by(a, b, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+}
+
+/// Same as [`is_subset`] but uses `cmp` to compare the elements. Both slices have to be sorted by
+/// `cmp`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::is_subset_by;
+/// assert!(is_subset_by(&[5, 2], &[7, 5, 3, 2], |a, b| b.cmp(a)));
+/// ```
+pub fn is_subset_by<T, F>(a: &[T], b: &[T], mut cmp: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     if a.len() > b.len() {
         return false;
     }
 
-    let mut a_iter = a.iter();
-
     let mut b_iter = b.iter();
 
-    loop {
-        let Some(a_val) = a_iter.next() else {
-            break;
-        };
+    for a_val in a {
+        loop {
+            let Some(b_val) = b_iter.next() else {
+                return false;
+            };
 
-        let Some(mut b_val) = b_iter.next() else {
-            return false;
-        };
+            match cmp(a_val, b_val) {
+                Ordering::Greater => continue,
+                Ordering::Equal => break,
+                Ordering::Less => return false,
+            }
+        }
+    }
 
-        while b_val < a_val {
-            b_val = match b_iter.next() {
-                Some(b) => b,
-                None => return false,
-            };
+    true
+}
+
+/// Same as [`is_subset`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn is_subset_by_key<T, K, F>(a: &[T], b: &[T], f: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    is_subset_by(a, b, by_key(f))
+}
+
+/// Inserts `item` into `vec` so, that its in its sorted position. Use [`extend_sorted`] to insert
+/// many items at once.
+#[inline]
+pub fn push_sorted<T: Ord>(vec: &mut Vec<T>, item: T) {
+    push_sorted_by(vec, item, T::cmp)
+}
+
+/// Same as [`push_sorted`] but uses `cmp` to compare the elements. `vec` has to be sorted by
+/// `cmp`, which allows descending order by reversing the comparison.
+///
+/// Example:
+///
+/// ```rust
+/// use std::cmp::Reverse;
+/// use utilsrs::vectools::{push_sorted_by, push_sorted_by_key};
+///
+/// let mut v = vec![9, 4, 1];
+/// push_sorted_by(&mut v, 5, |a, b| b.cmp(a));
+/// assert_eq!(v, vec![9, 5, 4, 1]);
+///
+/// let mut people = vec![("bob", 42), ("alice", 30)];
+/// push_sorted_by_key(&mut people, ("carol", 35), |p| Reverse(p.1));
+/// assert_eq!(people, vec![("bob", 42), ("carol", 35), ("alice", 30)]);
+/// ```
+pub fn push_sorted_by<T, F>(vec: &mut Vec<T>, item: T, mut cmp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (Ok(idx) | Err(idx)) = vec.binary_search_by(|a| cmp(a, &item));
+    vec.insert(idx, item);
+}
+
+/// Same as [`push_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn push_sorted_by_key<T, K, F>(vec: &mut Vec<T>, item: T, f: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    push_sorted_by(vec, item, by_key(f))
+}
+
+/// Inserts `item` into `vec` so, that its in its sorted position or does nothing if T is already a part of `vec`.
+/// Returns `true` if the value was inserted.
+#[inline]
+pub fn push_sorted_unique<T: Ord>(vec: &mut Vec<T>, item: T) -> bool {
+    push_sorted_unique_by(vec, item, T::cmp)
+}
+
+/// Same as [`push_sorted_unique`] but uses `cmp` to compare the elements.
+pub fn push_sorted_unique_by<T, F>(vec: &mut Vec<T>, item: T, mut cmp: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let idx = vec.binary_search_by(|a| cmp(a, &item));
+    if let Err(idx) = idx {
+        vec.insert(idx, item);
+        return true;
+    }
+
+    false
+}
+
+/// Same as [`push_sorted_unique`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn push_sorted_unique_by_key<T, K, F>(vec: &mut Vec<T>, item: T, f: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    push_sorted_unique_by(vec, item, by_key(f))
+}
+
+/// Inserts all `items` into the sorted `vec` so, that it stays sorted. Runs in O(n + m log m)
+/// unlike calling [`push_sorted`] in a loop, which is O(n·m).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::extend_sorted;
+/// let mut v = vec![1, 4, 7];
+/// extend_sorted(&mut v, vec![8, 0, 4]);
+/// assert_eq!(v, vec![0, 1, 4, 4, 7, 8]);
+/// ```
+#[inline]
+pub fn extend_sorted<T, I>(vec: &mut Vec<T>, items: I)
+where
+    T: Ord,
+    I: IntoIterator<Item = T>,
+{
+    extend_sorted_by(vec, items, T::cmp)
+}
+
+/// Same as [`extend_sorted`] but uses `cmp` to compare the elements. Elements which compare equal
+/// keep their relative order and items are placed after equal elements of `vec`.
+pub fn extend_sorted_by<T, I, F>(vec: &mut Vec<T>, items: I, mut cmp: F)
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut items = items.into_iter().collect::<Vec<_>>();
+    items.sort_by(&mut cmp);
+    merge_sorted_into_by(vec, items, cmp);
+}
+
+/// Same as [`extend_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn extend_sorted_by_key<T, I, K, F>(vec: &mut Vec<T>, items: I, f: F)
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    extend_sorted_by(vec, items, by_key(f))
+}
+
+/// Inserts all `items` which aren't part of the sorted `vec` yet, like calling
+/// [`push_sorted_unique`] for each of them. Returns the number of inserted items.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::extend_sorted_unique;
+/// let mut v = vec![1, 4, 7];
+/// assert_eq!(extend_sorted_unique(&mut v, vec![8, 4, 0, 8]), 2);
+/// assert_eq!(v, vec![0, 1, 4, 7, 8]);
+/// ```
+#[inline]
+pub fn extend_sorted_unique<T, I>(vec: &mut Vec<T>, items: I) -> usize
+where
+    T: Ord,
+    I: IntoIterator<Item = T>,
+{
+    extend_sorted_unique_by(vec, items, T::cmp)
+}
+
+/// Same as [`extend_sorted_unique`] but uses `cmp` to compare the elements. Of several items
+/// which compare equal only the first one is inserted.
+pub fn extend_sorted_unique_by<T, I, F>(vec: &mut Vec<T>, items: I, mut cmp: F) -> usize
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut items = items.into_iter().collect::<Vec<_>>();
+    items.sort_by(&mut cmp);
+    merge_sorted_unique_into_by(vec, items, cmp)
+}
+
+/// Same as [`extend_sorted_unique`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn extend_sorted_unique_by_key<T, I, K, F>(vec: &mut Vec<T>, items: I, f: F) -> usize
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    extend_sorted_unique_by(vec, items, by_key(f))
+}
+
+/// Merges the sorted `src` into the sorted `dest` in O(n + m). Elements of `src` are placed after
+/// equal elements of `dest`.
+#[inline]
+pub fn merge_sorted_into<T: Ord>(dest: &mut Vec<T>, src: Vec<T>) {
+    merge_sorted_into_by(dest, src, T::cmp);
+}
+
+/// Same as [`merge_sorted_into`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn merge_sorted_into_by<T, F>(dest: &mut Vec<T>, src: Vec<T>, cmp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_into_by(dest, src, false, cmp);
+}
+
+/// Same as [`merge_sorted_into`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn merge_sorted_into_by_key<T, K, F>(dest: &mut Vec<T>, src: Vec<T>, f: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sorted_into_by(dest, src, by_key(f));
+}
+
+/// Merges the sorted `src` into the sorted and deduplicated `dest`, skipping all elements which
+/// are already in `dest`. Returns the number of inserted elements.
+#[inline]
+pub fn merge_sorted_unique_into<T: Ord>(dest: &mut Vec<T>, src: Vec<T>) -> usize {
+    merge_sorted_unique_into_by(dest, src, T::cmp)
+}
+
+/// Same as [`merge_sorted_unique_into`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn merge_sorted_unique_into_by<T, F>(dest: &mut Vec<T>, src: Vec<T>, cmp: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_into_by(dest, src, true, cmp)
+}
+
+/// Same as [`merge_sorted_unique_into`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn merge_sorted_unique_into_by_key<T, K, F>(dest: &mut Vec<T>, src: Vec<T>, f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sorted_unique_into_by(dest, src, by_key(f))
+}
+
+/// Merges `src` into `dest`, both sorted by `cmp`, and returns the number of elements taken from
+/// `src`. If `unique` is set, elements equal to the previously pushed one are dropped.
+fn merge_into_by<T, F>(dest: &mut Vec<T>, src: Vec<T>, unique: bool, mut cmp: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if src.is_empty() {
+        return 0;
+    }
+
+    let old = std::mem::replace(dest, Vec::with_capacity(dest.len() + src.len()));
+    let mut src = src.into_iter().peekable();
+    let mut inserted = 0;
+
+    let mut push_src = |dest: &mut Vec<T>, item: T, cmp: &mut F| {
+        if unique && matches!(dest.last(), Some(last) if cmp(last, &item) == Ordering::Equal) {
+            return;
         }
+        dest.push(item);
+        inserted += 1;
+    };
 
-        if b_val > a_val {
-            return false;
+    for old_val in old {
+        while let Some(item) = src.next_if(|i| cmp(i, &old_val) == Ordering::Less) {
+            push_src(dest, item, &mut cmp);
         }
+        dest.push(old_val);
+    }
+    for item in src {
+        push_src(dest, item, &mut cmp);
     }
 
-    true
+    inserted
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::{
+        cmp::Reverse,
+        hash::{BuildHasherDefault, Hasher},
+    };
+    use test_case::test_case;
 
     #[test]
     fn test_contains() {
@@ -154,22 +679,130 @@ mod test {
         assert!(!contains(&a, &10, |a, b| a == b));
         assert!(!contains(&a, &100, |a, b| a == b));
     }
-}
 
-/// Inserts `item` into `vec` so, that its in its sorted position.
-pub fn push_sorted<T: Ord>(vec: &mut Vec<T>, item: T) {
-    let (Ok(idx) | Err(idx)) = vec.binary_search_by(|a| a.cmp(&item));
-    vec.insert(idx, item);
-}
+    #[test_case(&[], &[], true; "empty")]
+    #[test_case(&[1, 2, 3], &[3, 1, 2], true; "reordered")]
+    #[test_case(&[1, 1, 2], &[1, 2, 2], false; "different multiplicity")]
+    #[test_case(&[1, 2], &[1, 2, 2], false; "different length")]
+    fn test_same_elements(a: &[i32], b: &[i32], exp: bool) {
+        assert_eq!(same_elements(a, b), exp);
+        assert_eq!(same_elements_hash(a, b), exp);
+        assert_eq!(same_elements_ord(a, b), exp);
+    }
 
-/// Inserts `item` into `vec` so, that its in its sorted position or does nothing if T is already a part of `vec`.
-/// Returns `true` if the value was inserted.
-pub fn push_sorted_unique<T: Ord>(vec: &mut Vec<T>, item: T) -> bool {
-    let idx = vec.binary_search_by(|a| a.cmp(&item));
-    if let Err(idx) = idx {
-        vec.insert(idx, item);
-        return true;
+    #[test_case(&[], &[1], false; "empty")]
+    #[test_case(&[2, 1], &[1, 2, 3], true; "subset")]
+    #[test_case(&[1, 1], &[1, 2, 3], false; "missing duplicate")]
+    #[test_case(&[1, 1], &[1, 2, 1], true; "duplicate")]
+    #[test_case(&[4], &[1, 2, 3], false; "missing")]
+    fn test_part_of(a: &[i32], b: &[i32], exp: bool) {
+        assert_eq!(part_of(a, b), exp);
+        assert_eq!(part_of_hash(a, b), exp);
+        assert_eq!(part_of_ord(a, b), exp);
     }
 
-    false
+    #[test_case(&[], &[3, 1], &[1, 3]; "empty vec")]
+    #[test_case(&[1, 5], &[], &[1, 5]; "no items")]
+    #[test_case(&[1, 3, 5], &[6, 3, 0, 3], &[0, 1, 3, 3, 3, 5, 6]; "interleaved")]
+    fn test_extend_sorted(vec: &[i32], items: &[i32], exp: &[i32]) {
+        let mut v = vec.to_vec();
+        extend_sorted(&mut v, items.to_vec());
+        assert_eq!(v, exp);
+
+        let mut naive = vec.to_vec();
+        items.iter().for_each(|i| push_sorted(&mut naive, *i));
+        assert_eq!(v, naive);
+    }
+
+    #[test]
+    fn test_extend_sorted_unique() {
+        let mut v = vec![1, 3, 5];
+        assert_eq!(extend_sorted_unique(&mut v, vec![5, 2, 2, 6, 0, 1]), 3);
+        assert_eq!(v, vec![0, 1, 2, 3, 5, 6]);
+        assert_eq!(extend_sorted_unique(&mut v, Vec::new()), 0);
+    }
+
+    #[test]
+    fn test_extend_sorted_by_key() {
+        let mut v = vec![(1, 'a'), (3, 'a')];
+        extend_sorted_by_key(&mut v, vec![(3, 'b'), (1, 'b'), (2, 'b')], |i| i.0);
+        assert_eq!(v, vec![(1, 'a'), (1, 'b'), (2, 'b'), (3, 'a'), (3, 'b')]);
+
+        let mut v = vec![(1, 'a'), (3, 'a')];
+        let inserted =
+            extend_sorted_unique_by_key(&mut v, vec![(3, 'b'), (2, 'b'), (2, 'c')], |i| i.0);
+        assert_eq!(inserted, 1);
+        assert_eq!(v, vec![(1, 'a'), (2, 'b'), (3, 'a')]);
+    }
+
+    #[test]
+    fn test_by_key() {
+        let mut v = vec![(3, 'a'), (1, 'a')];
+        push_sorted_by_key(&mut v, (2, 'b'), |i| Reverse(i.0));
+        assert!(!push_sorted_unique_by_key(&mut v, (3, 'b'), |i| Reverse(
+            i.0
+        )));
+        assert!(push_sorted_unique_by_key(&mut v, (0, 'b'), |i| Reverse(
+            i.0
+        )));
+        assert_eq!(v, vec![(3, 'a'), (2, 'b'), (1, 'a'), (0, 'b')]);
+
+        assert!(is_subset_by_key(&[(2, 'x'), (0, 'x')], &v, |i| Reverse(
+            i.0
+        )));
+        assert!(!is_subset_by_key(&[(4, 'x')], &v, |i| Reverse(i.0)));
+        assert!(!is_subset_by(&[0, 2], &[3, 2, 1, 0], |a, b| b.cmp(a)));
+
+        let mut desc = vec![9, 5, 1];
+        merge_sorted_into_by(&mut desc, vec![6, 5, 0], |a, b| b.cmp(a));
+        assert_eq!(desc, vec![9, 6, 5, 5, 1, 0]);
+    }
+
+    #[test_case(&[], &[], &[]; "empty")]
+    #[test_case(&[1, 2, 3], &[1, 2, 3], &[]; "unique")]
+    #[test_case(&[2, 2, 1, 2, 3, 1], &[2, 1, 3], &[2, 2, 1]; "dups")]
+    fn test_remove_dups(inp: &[i32], unique: &[i32], dups: &[i32]) {
+        assert_eq!(remove_dups(inp.to_vec()), unique);
+        assert_eq!(remove_dups_hash(inp.to_vec()), unique);
+        assert_eq!(remove_dups_by_key(inp.to_vec(), |i| *i), unique);
+
+        let mut v = inp.to_vec();
+        assert_eq!(retain_unique(&mut v), dups.len());
+        assert_eq!(v, unique);
+
+        let (u, d) = split_dups(inp.to_vec());
+        assert_eq!((u.as_slice(), d.as_slice()), (unique, dups));
+    }
+
+    #[test]
+    fn retain_unique_colliding() {
+        // Every element has the same hash, so all lookups after the first one collide.
+        #[derive(Default)]
+        struct Constant;
+
+        impl Hasher for Constant {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, _: &[u8]) {}
+        }
+
+        let mut v = vec![3, 1, 3, 2, 1, 2, 4];
+        let removed = retain_unique_with(&mut v, &BuildHasherDefault::<Constant>::default());
+        assert_eq!((removed, v), (3, vec![3, 1, 2, 4]));
+    }
+
+    #[test]
+    fn test_dups_by_key() {
+        let words = vec!["Apple", "avocado", "Banana", "apple", "cherry"];
+        let first = |w: &&str| w.chars().next().unwrap().to_ascii_lowercase();
+
+        let mut v = words.clone();
+        assert_eq!(retain_unique_by_key(&mut v, first), 2);
+        assert_eq!(v, vec!["Apple", "Banana", "cherry"]);
+
+        let (_, dups) = split_dups_by_key(words, first);
+        assert_eq!(dups, vec!["avocado", "apple"]);
+    }
 }
@@ -0,0 +1,268 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
+
+/// Maps the elements of a slice to their position, to answer repeated order queries in O(1)
+/// instead of scanning the slice like [`get_item_order`](super::get_item_order) does.
+///
+/// Example:
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// use utilsrs::vectools::OrderIndex;
+/// let order = ["low", "medium", "high"];
+/// let index = OrderIndex::new(&order);
+/// assert_eq!(index.position(&"high"), Some(2));
+/// assert_eq!(index.order(&"high", &"low"), Some(Ordering::Greater));
+/// assert_eq!(index.order(&"high", &"unknown"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderIndex<'a, T> {
+    positions: HashMap<&'a T, usize>,
+}
+
+impl<'a, T: Hash + Eq> OrderIndex<'a, T> {
+    /// Creates the index. Elements occurring multiple times are mapped to their first position.
+    pub fn new(order: &'a [T]) -> Self {
+        let mut positions = HashMap::with_capacity(order.len());
+        for (pos, item) in order.iter().enumerate() {
+            positions.entry(item).or_insert(pos);
+        }
+        Self { positions }
+    }
+
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize> {
+        self.positions.get(item).copied()
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.positions.contains_key(item)
+    }
+
+    /// Returns the relative order of `a` and `b`, or `None` if one of them isn't indexed.
+    #[inline]
+    pub fn order(&self, a: &T, b: &T) -> Option<Ordering> {
+        Some(self.position(a)?.cmp(&self.position(b)?))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
+
+/// Sorts `items` by the position of each item in `order`. Items which aren't part of `order` are
+/// moved to the end. The sort is stable.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::sort_by_reference;
+/// let mut items = vec!["b", "x", "c", "a", "b"];
+/// sort_by_reference(&mut items, &["a", "b", "c"]);
+/// assert_eq!(items, vec!["a", "b", "b", "c", "x"]);
+/// ```
+#[inline]
+pub fn sort_by_reference<T: Hash + Eq>(items: &mut [T], order: &[T]) {
+    sort_by_reference_key(items, order, |i| i)
+}
+
+/// Same as [`sort_by_reference`] but looks up the key returned by `f` in `order`.
+pub fn sort_by_reference_key<T, K, F>(items: &mut [T], order: &[K], mut f: F)
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> &K,
+{
+    let index = OrderIndex::new(order);
+    items.sort_by_cached_key(|i| index.position(f(i)).unwrap_or(usize::MAX));
+}
+
+/// Error returned by [`topological_sort`] if the constraints contradict each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<T> {
+    /// The elements forming the cycle. Each element is required to come before the next one and
+    /// the last one before the first one.
+    pub cycle: Vec<T>,
+}
+
+impl<T: Debug> Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ordering constraints contain a cycle: ")?;
+        for item in &self.cycle {
+            write!(f, "{:?} -> ", item)?;
+        }
+        match self.cycle.first() {
+            Some(first) => write!(f, "{:?}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Debug> Error for CycleError<T> {}
+
+/// Returns a total order of all elements which satisfies the `constraints`, where `(a, b)` means
+/// that `a` has to come before `b`. Out of all elements whose predecessors are already placed, the
+/// one appearing first in the constraints comes next, so independent elements keep the order in
+/// which they first appear. Returns the offending cycle if the constraints can't be satisfied.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::topological_sort;
+/// let order = topological_sort(vec![("wake", "shower"), ("shower", "dress"), ("wake", "coffee")]);
+/// assert_eq!(order.unwrap(), vec!["wake", "shower", "dress", "coffee"]);
+///
+/// let err = topological_sort(vec![(1, 2), (2, 3), (3, 1), (0, 1)]).unwrap_err();
+/// assert_eq!(err.cycle, vec![1, 2, 3]);
+/// ```
+pub fn topological_sort<T, I>(constraints: I) -> Result<Vec<T>, CycleError<T>>
+where
+    T: Hash + Eq + Clone,
+    I: IntoIterator<Item = (T, T)>,
+{
+    let mut ids: HashMap<T, usize> = HashMap::new();
+    let mut nodes: Vec<T> = Vec::new();
+    let mut id_of = |item: T, nodes: &mut Vec<T>| {
+        *ids.entry(item.clone()).or_insert_with(|| {
+            nodes.push(item);
+            nodes.len() - 1
+        })
+    };
+
+    let mut edges = Vec::new();
+    for (a, b) in constraints {
+        let a = id_of(a, &mut nodes);
+        let b = id_of(b, &mut nodes);
+        edges.push((a, b));
+    }
+
+    let mut succ = vec![Vec::new(); nodes.len()];
+    let mut pred = vec![Vec::new(); nodes.len()];
+    let mut in_degree = vec![0usize; nodes.len()];
+    for (a, b) in edges {
+        succ[a].push(b);
+        pred[b].push(a);
+        in_degree[b] += 1;
+    }
+
+    // Kahn's algorithm, always taking the available node with the smallest id. The ids are
+    // assigned in order of first appearance.
+    let mut available = (0..nodes.len())
+        .filter(|i| in_degree[*i] == 0)
+        .map(Reverse)
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(Reverse(node)) = available.pop() {
+        order.push(node);
+        for &next in &succ[node] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                available.push(Reverse(next));
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        let cycle = find_cycle(&pred, &in_degree);
+        return Err(CycleError {
+            cycle: cycle.into_iter().map(|i| nodes[i].clone()).collect(),
+        });
+    }
+
+    Ok(order.into_iter().map(|i| nodes[i].clone()).collect())
+}
+
+/// Finds a cycle among the nodes which Kahn's algorithm couldn't process. Each of them still has a
+/// predecessor which is unprocessed too, so walking backwards eventually revisits a node.
+fn find_cycle(pred: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+    let remaining = |i: usize| in_degree[i] > 0;
+
+    let mut visited_at = vec![None; pred.len()];
+    let mut path = Vec::new();
+    let mut node = (0..pred.len()).find(|i| remaining(*i)).unwrap();
+
+    while visited_at[node].is_none() {
+        visited_at[node] = Some(path.len());
+        path.push(node);
+        node = pred[node].iter().copied().find(|p| remaining(*p)).unwrap();
+    }
+
+    // `path` follows the edges backwards, so reverse the cycle and start it at its smallest id.
+    let mut cycle = path.split_off(visited_at[node].unwrap());
+    cycle.reverse();
+    let start = cycle.iter().enumerate().min_by_key(|(_, n)| **n).unwrap().0;
+    cycle.rotate_left(start);
+    cycle
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn topo_order_respects_constraints() {
+        let constraints = vec![
+            (5, 11),
+            (7, 11),
+            (7, 8),
+            (3, 8),
+            (3, 10),
+            (11, 2),
+            (11, 9),
+            (8, 9),
+        ];
+        let order = topological_sort(constraints.clone()).unwrap();
+        let index = OrderIndex::new(&order);
+        assert_eq!(index.len(), 8);
+        for (a, b) in constraints {
+            assert_eq!(index.order(&a, &b), Some(Ordering::Less));
+        }
+    }
+
+    #[test]
+    fn topo_first_appearance() {
+        assert_eq!(
+            topological_sort(vec![("a", "b"), ("c", "d")]),
+            Ok(vec!["a", "b", "c", "d"])
+        );
+        assert_eq!(
+            topological_sort(vec![(3, 1), (2, 1), (4, 2)]),
+            Ok(vec![3, 4, 2, 1])
+        );
+    }
+
+    #[test]
+    fn topo_cycles() {
+        assert_eq!(
+            topological_sort(vec![('a', 'a')]).unwrap_err().cycle,
+            vec!['a']
+        );
+
+        let err = topological_sort(vec![(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]).unwrap_err();
+        assert_eq!(err.cycle, vec![1, 2, 3]);
+        assert_eq!(
+            err.to_string(),
+            "ordering constraints contain a cycle: 1 -> 2 -> 3 -> 1"
+        );
+
+        assert_eq!(topological_sort(Vec::<(u8, u8)>::new()), Ok(vec![]));
+    }
+
+    #[test]
+    fn reference_sort() {
+        let mut items = vec![(3, 'x'), (1, 'y'), (9, 'z'), (3, 'w')];
+        sort_by_reference_key(&mut items, &[1, 2, 3], |i| &i.0);
+        assert_eq!(items, vec![(1, 'y'), (3, 'x'), (3, 'w'), (9, 'z')]);
+    }
+}
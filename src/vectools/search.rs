@@ -0,0 +1,208 @@
+use std::{cmp::Ordering, ops::Range};
+
+// The `_by` variants take a function returning the ordering of an element relative to the target,
+// like `slice::binary_search_by`. The slice has to be sorted consistently with it.
+
+/// Returns the index of the first element which is not less than `item`, or the length of `slice`
+/// if there is none.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{lower_bound, upper_bound, equal_range};
+/// let v = [1, 2, 2, 2, 5];
+/// assert_eq!(lower_bound(&v, &2), 1);
+/// assert_eq!(upper_bound(&v, &2), 4);
+/// assert_eq!(equal_range(&v, &2), 1..4);
+/// assert_eq!(equal_range(&v, &3), 4..4);
+/// ```
+#[inline]
+pub fn lower_bound<T: Ord>(slice: &[T], item: &T) -> usize {
+    lower_bound_by(slice, |e| e.cmp(item))
+}
+
+/// Same as [`lower_bound`] but uses `f` to compare the elements with the target.
+#[inline]
+pub fn lower_bound_by<T, F>(slice: &[T], mut f: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    slice.partition_point(|e| f(e) == Ordering::Less)
+}
+
+/// Same as [`lower_bound`] but compares the key returned by `f` with `key`.
+#[inline]
+pub fn lower_bound_by_key<T, K, F>(slice: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    lower_bound_by(slice, |e| f(e).cmp(key))
+}
+
+/// Returns the index of the first element which is greater than `item`, or the length of `slice`
+/// if there is none.
+#[inline]
+pub fn upper_bound<T: Ord>(slice: &[T], item: &T) -> usize {
+    upper_bound_by(slice, |e| e.cmp(item))
+}
+
+/// Same as [`upper_bound`] but uses `f` to compare the elements with the target.
+#[inline]
+pub fn upper_bound_by<T, F>(slice: &[T], mut f: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    slice.partition_point(|e| f(e) != Ordering::Greater)
+}
+
+/// Same as [`upper_bound`] but compares the key returned by `f` with `key`.
+#[inline]
+pub fn upper_bound_by_key<T, K, F>(slice: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    upper_bound_by(slice, |e| f(e).cmp(key))
+}
+
+/// Returns the range of all elements equal to `item`. The range is empty and starts at the
+/// insertion position if there is no such element.
+#[inline]
+pub fn equal_range<T: Ord>(slice: &[T], item: &T) -> Range<usize> {
+    equal_range_by(slice, |e| e.cmp(item))
+}
+
+/// Same as [`equal_range`] but uses `f` to compare the elements with the target.
+pub fn equal_range_by<T, F>(slice: &[T], mut f: F) -> Range<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let start = lower_bound_by(slice, &mut f);
+    let end = start + upper_bound_by(&slice[start..], f);
+    start..end
+}
+
+/// Same as [`equal_range`] but compares the key returned by `f` with `key`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::equal_range_by_key;
+/// let people = [("ann", 20), ("bob", 30), ("cat", 30), ("dan", 41)];
+/// let range = equal_range_by_key(&people, &30, |p| p.1);
+/// assert_eq!(&people[range], &[("bob", 30), ("cat", 30)]);
+/// ```
+#[inline]
+pub fn equal_range_by_key<T, K, F>(slice: &[T], key: &K, mut f: F) -> Range<usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    equal_range_by(slice, |e| f(e).cmp(key))
+}
+
+/// Same as `slice::partition_point` but applies `pred` to the key returned by `f`. Returns the
+/// index of the first element whose key doesn't satisfy `pred`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::partition_point_by_key;
+/// let words = ["a", "to", "the", "tree", "forest"];
+/// assert_eq!(partition_point_by_key(&words, |w| w.len(), |len| *len < 4), 3);
+/// ```
+#[inline]
+pub fn partition_point_by_key<T, K, F, P>(slice: &[T], mut f: F, mut pred: P) -> usize
+where
+    F: FnMut(&T) -> K,
+    P: FnMut(&K) -> bool,
+{
+    slice.partition_point(|e| pred(&f(e)))
+}
+
+/// Same as `slice::binary_search` but first doubles the searched range from the start of `slice`
+/// until it contains `item`. This runs in O(log i), where `i` is the position of `item`, and is
+/// faster than a binary search if the item is close to the start. Unlike `binary_search`, the
+/// first of several equal elements is returned.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::exponential_search;
+/// let v = (0..1000).map(|i| i * 2).collect::<Vec<_>>();
+/// assert_eq!(exponential_search(&v, &6), Ok(3));
+/// assert_eq!(exponential_search(&v, &7), Err(4));
+/// ```
+#[inline]
+pub fn exponential_search<T: Ord>(slice: &[T], item: &T) -> Result<usize, usize> {
+    exponential_search_by(slice, |e| e.cmp(item))
+}
+
+/// Same as [`exponential_search`] but uses `f` to compare the elements with the target.
+pub fn exponential_search_by<T, F>(slice: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut bound = 1;
+    while bound < slice.len() && f(&slice[bound]) == Ordering::Less {
+        bound *= 2;
+    }
+
+    // Everything before `bound / 2` is less than the target.
+    let start = bound / 2;
+    let end = (bound + 1).min(slice.len());
+    let idx = start + lower_bound_by(&slice[start..end], &mut f);
+
+    match slice.get(idx) {
+        Some(e) if f(e) == Ordering::Equal => Ok(idx),
+        _ => Err(idx),
+    }
+}
+
+/// Same as [`exponential_search`] but compares the key returned by `f` with `key`.
+#[inline]
+pub fn exponential_search_by_key<T, K, F>(slice: &[T], key: &K, mut f: F) -> Result<usize, usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    exponential_search_by(slice, |e| f(e).cmp(key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cmp::Reverse;
+    use test_case::test_case;
+
+    #[test_case(&[]; "empty")]
+    #[test_case(&[3]; "single")]
+    #[test_case(&[1, 1, 1, 1]; "all equal")]
+    #[test_case(&[0, 1, 1, 2, 4, 4, 4, 7, 8, 8, 9, 12, 12, 12, 13]; "mixed")]
+    fn against_linear(v: &[i32]) {
+        for x in -1..15 {
+            let lower = v.iter().take_while(|e| **e < x).count();
+            let upper = v.iter().take_while(|e| **e <= x).count();
+            assert_eq!(lower_bound(v, &x), lower);
+            assert_eq!(upper_bound(v, &x), upper);
+            assert_eq!(equal_range(v, &x), lower..upper);
+
+            let exp = if lower < upper { Ok(lower) } else { Err(lower) };
+            assert_eq!(exponential_search(v, &x), exp);
+        }
+    }
+
+    #[test]
+    fn descending() {
+        let v = [9, 7, 7, 3, 1];
+        assert_eq!(equal_range_by(&v, |e| 7.cmp(e)), 1..3);
+        assert_eq!(equal_range_by_key(&v, &Reverse(7), |e| Reverse(*e)), 1..3);
+        assert_eq!(
+            exponential_search_by_key(&v, &Reverse(3), |e| Reverse(*e)),
+            Ok(3)
+        );
+        assert_eq!(lower_bound_by_key(&v, &Reverse(5), |e| Reverse(*e)), 3);
+        assert_eq!(upper_bound_by_key(&v, &Reverse(9), |e| Reverse(*e)), 1);
+    }
+}
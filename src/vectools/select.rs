@@ -0,0 +1,271 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+/// Returns the `k` largest items of `iter` in descending order. Only `k` items are kept in memory
+/// at a time, so this runs in O(n log k).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{bottom_k, top_k};
+/// let v = vec![5, 1, 9, 3, 7, 9];
+/// assert_eq!(top_k(v.iter().copied(), 3), vec![9, 9, 7]);
+/// assert_eq!(bottom_k(v, 2), vec![1, 3]);
+/// ```
+pub fn top_k<T, I>(iter: I, k: usize) -> Vec<T>
+where
+    T: Ord,
+    I: IntoIterator<Item = T>,
+{
+    let mut heap = BinaryHeap::new();
+    for item in iter {
+        push_bounded(&mut heap, Reverse(item), k);
+    }
+    heap.into_sorted_vec().into_iter().map(|i| i.0).collect()
+}
+
+/// Returns the `k` smallest items of `iter` in ascending order. Runs in O(n log k).
+pub fn bottom_k<T, I>(iter: I, k: usize) -> Vec<T>
+where
+    T: Ord,
+    I: IntoIterator<Item = T>,
+{
+    let mut heap = BinaryHeap::new();
+    for item in iter {
+        push_bounded(&mut heap, item, k);
+    }
+    heap.into_sorted_vec()
+}
+
+/// Same as [`top_k`] but compares the items by the key returned by `f`. Of several items with the
+/// same key the ones seen first are preferred.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::top_k_by_key;
+/// let scores = vec![("ann", 71), ("bob", 93), ("cat", 85), ("dan", 93)];
+/// assert_eq!(top_k_by_key(scores, 2, |s| s.1), vec![("bob", 93), ("dan", 93)]);
+/// ```
+pub fn top_k_by_key<T, I, K, F>(iter: I, k: usize, mut f: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut heap = BinaryHeap::new();
+    for (idx, item) in iter.into_iter().enumerate() {
+        let key = (f(&item), Reverse(idx));
+        push_bounded(&mut heap, Reverse(Keyed(key, item)), k);
+    }
+    heap.into_sorted_vec().into_iter().map(|i| i.0 .1).collect()
+}
+
+/// Same as [`bottom_k`] but compares the items by the key returned by `f`. Of several items with
+/// the same key the ones seen first are preferred.
+pub fn bottom_k_by_key<T, I, K, F>(iter: I, k: usize, mut f: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut heap = BinaryHeap::new();
+    for (idx, item) in iter.into_iter().enumerate() {
+        push_bounded(&mut heap, Keyed((f(&item), idx), item), k);
+    }
+    heap.into_sorted_vec().into_iter().map(|i| i.1).collect()
+}
+
+/// Pushes `item` into the max-heap `heap` while keeping at most the `k` smallest items.
+#[inline]
+fn push_bounded<T: Ord>(heap: &mut BinaryHeap<T>, item: T, k: usize) {
+    if heap.len() < k {
+        heap.push(item);
+    } else if let Some(mut largest) = heap.peek_mut() {
+        if item < *largest {
+            *largest = item;
+        }
+    }
+}
+
+/// An item which is ordered by its key only.
+struct Keyed<K, T>(K, T);
+
+impl<K: PartialEq, T> PartialEq for Keyed<K, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Returns the `k`-th smallest element of `slice`, counting from zero, or `None` if `k` is out of
+/// bounds. Reorders `slice` so that all elements before `k` are less than or equal to it and all
+/// elements after it are greater than or equal to it.
+///
+/// This uses `slice::select_nth_unstable`, an introselect which falls back to median of medians
+/// and therefore runs in O(n) even in the worst case.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{kth_largest, kth_smallest};
+/// let mut v = vec![7, 1, 5, 3, 9, 5];
+/// assert_eq!(kth_smallest(&mut v, 0), Some(&1));
+/// assert_eq!(kth_smallest(&mut v, 3), Some(&5));
+/// assert_eq!(kth_largest(&mut v, 0), Some(&9));
+/// assert_eq!(kth_smallest(&mut v, 6), None);
+/// ```
+#[inline]
+pub fn kth_smallest<T: Ord>(slice: &mut [T], k: usize) -> Option<&T> {
+    kth_smallest_by(slice, k, T::cmp)
+}
+
+/// Same as [`kth_smallest`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn kth_smallest_by<T, F>(slice: &mut [T], k: usize, cmp: F) -> Option<&T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if k >= slice.len() {
+        return None;
+    }
+    Some(slice.select_nth_unstable_by(k, cmp).1)
+}
+
+/// Same as [`kth_smallest`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn kth_smallest_by_key<T, K, F>(slice: &mut [T], k: usize, f: F) -> Option<&T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    if k >= slice.len() {
+        return None;
+    }
+    Some(slice.select_nth_unstable_by_key(k, f).1)
+}
+
+/// Returns the `k`-th largest element of `slice`, counting from zero. See [`kth_smallest`].
+#[inline]
+pub fn kth_largest<T: Ord>(slice: &mut [T], k: usize) -> Option<&T> {
+    kth_smallest_by(slice, k, |a, b| b.cmp(a))
+}
+
+/// Moves the `k` smallest elements of `slice` to its front in ascending order and returns them.
+/// The order of the remaining elements is unspecified. Runs in O(n + k log k).
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::partial_sort;
+/// let mut v = vec![8, 3, 9, 1, 4, 7];
+/// assert_eq!(partial_sort(&mut v, 3), &[1, 3, 4]);
+/// assert_eq!(&v[..3], &[1, 3, 4]);
+/// ```
+#[inline]
+pub fn partial_sort<T: Ord>(slice: &mut [T], k: usize) -> &mut [T] {
+    partial_sort_by(slice, k, T::cmp)
+}
+
+/// Same as [`partial_sort`] but uses `cmp` to compare the elements.
+pub fn partial_sort_by<T, F>(slice: &mut [T], k: usize, mut cmp: F) -> &mut [T]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let k = k.min(slice.len());
+    if k == 0 {
+        return &mut slice[..0];
+    }
+    if k < slice.len() {
+        slice.select_nth_unstable_by(k - 1, &mut cmp);
+    }
+    let front = &mut slice[..k];
+    front.sort_unstable_by(cmp);
+    front
+}
+
+/// Same as [`partial_sort`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn partial_sort_by_key<T, K, F>(slice: &mut [T], k: usize, mut f: F) -> &mut [T]
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    partial_sort_by(slice, k, |a, b| f(a).cmp(&f(b)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn data() -> Vec<u32> {
+        // Deterministic pseudo random values with many duplicates.
+        (0..200u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) % 97)
+            .collect()
+    }
+
+    #[test_case(0; "zero")]
+    #[test_case(1; "one")]
+    #[test_case(17; "some")]
+    #[test_case(200; "all")]
+    #[test_case(500; "more than all")]
+    fn k_items(k: usize) {
+        let mut sorted = data();
+        sorted.sort_unstable();
+        let n = k.min(sorted.len());
+
+        assert_eq!(bottom_k(data(), k), &sorted[..n]);
+        assert_eq!(bottom_k_by_key(data(), k, |i| *i), &sorted[..n]);
+
+        let mut v = data();
+        assert_eq!(partial_sort(&mut v, k), &sorted[..n]);
+
+        sorted.reverse();
+        assert_eq!(top_k(data(), k), &sorted[..n]);
+        assert_eq!(top_k_by_key(data(), k, |i| *i), &sorted[..n]);
+    }
+
+    #[test]
+    fn kth() {
+        let mut sorted = data();
+        sorted.sort_unstable();
+        for k in 0..sorted.len() {
+            let mut v = data();
+            assert_eq!(kth_smallest(&mut v, k), Some(&sorted[k]));
+            assert!(v[..k].iter().all(|i| *i <= sorted[k]));
+            assert!(v[k + 1..].iter().all(|i| *i >= sorted[k]));
+            assert_eq!(kth_largest(&mut v, k), Some(&sorted[sorted.len() - 1 - k]));
+        }
+        assert_eq!(kth_smallest::<u8>(&mut [], 0), None);
+    }
+
+    #[test]
+    fn stable_ties() {
+        let items = vec![(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd'), (0, 'e')];
+        assert_eq!(
+            bottom_k_by_key(items.clone(), 3, |i| i.0),
+            vec![(0, 'e'), (1, 'a'), (1, 'c')]
+        );
+        assert_eq!(top_k_by_key(items, 1, |i| i.0), vec![(2, 'b')]);
+    }
+}
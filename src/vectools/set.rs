@@ -0,0 +1,449 @@
+use std::{cmp::Ordering, collections::HashSet, hash::Hash, iter::Peekable, slice::Iter};
+
+/// Returns an iterator over all elements of `a` followed by the elements of `b` which are not in
+/// `a`. Runs in O(n·m).
+///
+/// This and the other unsorted set operations only check whether an element is contained in the
+/// other slice. Elements are yielded in the order of the input slices and duplicates within a slice
+/// are kept, so `union(&[1, 1], &[1])` yields `1` twice. Use [`union_sorted`] for multisets.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::union;
+/// let res = union(&[1, 2, 3], &[4, 2, 5]).copied().collect::<Vec<_>>();
+/// assert_eq!(res, vec![1, 2, 3, 4, 5]);
+/// ```
+#[inline]
+pub fn union<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    a.iter().chain(difference(b, a))
+}
+
+/// Returns an iterator over all elements of `a` which are also in `b`, keeping duplicates of `a`.
+/// Runs in O(n·m).
+#[inline]
+pub fn intersection<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    a.iter().filter(move |i| b.contains(i))
+}
+
+/// Returns an iterator over all elements of `a` which are not in `b`, keeping duplicates of `a`.
+/// Runs in O(n·m).
+#[inline]
+pub fn difference<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    a.iter().filter(move |i| !b.contains(i))
+}
+
+/// Returns an iterator over all elements of `a` which are not in `b`, followed by all elements of
+/// `b` which are not in `a`. Runs in O(n·m).
+#[inline]
+pub fn symmetric_difference<'a, T: PartialEq>(
+    a: &'a [T],
+    b: &'a [T],
+) -> impl Iterator<Item = &'a T> {
+    difference(a, b).chain(difference(b, a))
+}
+
+/// Returns `true` if `a` and `b` have no element in common. Runs in O(n·m).
+#[inline]
+pub fn is_disjoint<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    intersection(a, b).next().is_none()
+}
+
+/// Same as [`union`] but runs in O(n + m) using a `HashSet`.
+#[inline]
+pub fn union_hash<'a, T: Hash + Eq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    a.iter().chain(difference_hash(b, a))
+}
+
+/// Same as [`intersection`] but runs in O(n + m) using a `HashSet`.
+#[inline]
+pub fn intersection_hash<'a, T: Hash + Eq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    let b = b.iter().collect::<HashSet<_>>();
+    a.iter().filter(move |i| b.contains(i))
+}
+
+/// Same as [`difference`] but runs in O(n + m) using a `HashSet`.
+#[inline]
+pub fn difference_hash<'a, T: Hash + Eq>(a: &'a [T], b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    let b = b.iter().collect::<HashSet<_>>();
+    a.iter().filter(move |i| !b.contains(i))
+}
+
+/// Same as [`symmetric_difference`] but runs in O(n + m) using a `HashSet`.
+#[inline]
+pub fn symmetric_difference_hash<'a, T: Hash + Eq>(
+    a: &'a [T],
+    b: &'a [T],
+) -> impl Iterator<Item = &'a T> {
+    difference_hash(a, b).chain(difference_hash(b, a))
+}
+
+/// Same as [`is_disjoint`] but runs in O(n + m) using a `HashSet`.
+#[inline]
+pub fn is_disjoint_hash<T: Hash + Eq>(a: &[T], b: &[T]) -> bool {
+    intersection_hash(a, b).next().is_none()
+}
+
+/// Returns an iterator over the sorted union of the sorted slices `a` and `b`. Elements occurring in
+/// both slices are yielded once per matching pair. Runs in O(n + m).
+///
+/// Unlike [`union`], the sorted set operations treat their inputs as multisets: each occurrence
+/// in one slice is matched with at most one equal occurrence in the other slice.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::union_sorted;
+/// let res = union_sorted(&[1, 1, 3, 5], &[1, 2, 3]).copied().collect::<Vec<_>>();
+/// assert_eq!(res, vec![1, 1, 2, 3, 5]);
+/// ```
+#[inline]
+pub fn union_sorted<'a, T: Ord>(a: &'a [T], b: &'a [T]) -> SortedSetIter<'a, T> {
+    SortedSetIter::new(a, b, SetOp::Union, T::cmp)
+}
+
+/// Same as [`union_sorted`] but uses `cmp` to compare the elements. The slices have to be sorted
+/// by `cmp` as well, so a reversed comparator works on slices sorted in descending order.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::union_sorted_by;
+/// let res = union_sorted_by(&[5, 3, 1], &[4, 3], |a, b| b.cmp(a));
+/// assert_eq!(res.copied().collect::<Vec<_>>(), vec![5, 4, 3, 1]);
+/// ```
+#[inline]
+pub fn union_sorted_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> SortedSetIter<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortedSetIter::new(a, b, SetOp::Union, cmp)
+}
+
+/// Same as [`union_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn union_sorted_by_key<'a, T, K, F>(
+    a: &'a [T],
+    b: &'a [T],
+    f: F,
+) -> SortedSetIter<'a, T, impl FnMut(&T, &T) -> Ordering>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    union_sorted_by(a, b, by_key(f))
+}
+
+/// Returns an iterator over the sorted intersection of the sorted slices `a` and `b`. An element
+/// is yielded as often as it occurs in the slice containing it fewer times. Runs in O(n + m).
+#[inline]
+pub fn intersection_sorted<'a, T: Ord>(a: &'a [T], b: &'a [T]) -> SortedSetIter<'a, T> {
+    SortedSetIter::new(a, b, SetOp::Intersection, T::cmp)
+}
+
+/// Same as [`intersection_sorted`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn intersection_sorted_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> SortedSetIter<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortedSetIter::new(a, b, SetOp::Intersection, cmp)
+}
+
+/// Same as [`intersection_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn intersection_sorted_by_key<'a, T, K, F>(
+    a: &'a [T],
+    b: &'a [T],
+    f: F,
+) -> SortedSetIter<'a, T, impl FnMut(&T, &T) -> Ordering>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    intersection_sorted_by(a, b, by_key(f))
+}
+
+/// Returns an iterator over the elements of the sorted slice `a` which aren't matched by an
+/// element of the sorted slice `b`. Each occurrence in `b` cancels out one occurrence in `a`.
+/// Runs in O(n + m).
+#[inline]
+pub fn difference_sorted<'a, T: Ord>(a: &'a [T], b: &'a [T]) -> SortedSetIter<'a, T> {
+    SortedSetIter::new(a, b, SetOp::Difference, T::cmp)
+}
+
+/// Same as [`difference_sorted`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn difference_sorted_by<'a, T, F>(a: &'a [T], b: &'a [T], cmp: F) -> SortedSetIter<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortedSetIter::new(a, b, SetOp::Difference, cmp)
+}
+
+/// Same as [`difference_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn difference_sorted_by_key<'a, T, K, F>(
+    a: &'a [T],
+    b: &'a [T],
+    f: F,
+) -> SortedSetIter<'a, T, impl FnMut(&T, &T) -> Ordering>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    difference_sorted_by(a, b, by_key(f))
+}
+
+/// Returns an iterator over the sorted elements of the sorted slices `a` and `b` which aren't
+/// matched by an element of the other slice. Runs in O(n + m).
+#[inline]
+pub fn symmetric_difference_sorted<'a, T: Ord>(a: &'a [T], b: &'a [T]) -> SortedSetIter<'a, T> {
+    SortedSetIter::new(a, b, SetOp::SymmetricDifference, T::cmp)
+}
+
+/// Same as [`symmetric_difference_sorted`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn symmetric_difference_sorted_by<'a, T, F>(
+    a: &'a [T],
+    b: &'a [T],
+    cmp: F,
+) -> SortedSetIter<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortedSetIter::new(a, b, SetOp::SymmetricDifference, cmp)
+}
+
+/// Same as [`symmetric_difference_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn symmetric_difference_sorted_by_key<'a, T, K, F>(
+    a: &'a [T],
+    b: &'a [T],
+    f: F,
+) -> SortedSetIter<'a, T, impl FnMut(&T, &T) -> Ordering>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    symmetric_difference_sorted_by(a, b, by_key(f))
+}
+
+/// Returns `true` if the sorted slices `a` and `b` have no element in common. Runs in O(n + m).
+#[inline]
+pub fn is_disjoint_sorted<T: Ord>(a: &[T], b: &[T]) -> bool {
+    intersection_sorted(a, b).next().is_none()
+}
+
+/// Same as [`is_disjoint_sorted`] but uses `cmp` to compare the elements.
+#[inline]
+pub fn is_disjoint_sorted_by<T, F>(a: &[T], b: &[T], cmp: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    intersection_sorted_by(a, b, cmp).next().is_none()
+}
+
+/// Same as [`is_disjoint_sorted`] but compares the elements by the key returned by `f`.
+#[inline]
+pub fn is_disjoint_sorted_by_key<T, K, F>(a: &[T], b: &[T], f: F) -> bool
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    intersection_sorted_by_key(a, b, f).next().is_none()
+}
+
+/// Turns a key function into a comparator.
+#[inline]
+pub(crate) fn by_key<T, K, F>(mut f: F) -> impl FnMut(&T, &T) -> Ordering
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    move |a, b| f(a).cmp(&f(b))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+impl SetOp {
+    /// Whether elements only occurring in the left slice are yielded.
+    #[inline]
+    fn keeps_left(self) -> bool {
+        self != SetOp::Intersection
+    }
+
+    /// Whether elements only occurring in the right slice are yielded.
+    #[inline]
+    fn keeps_right(self) -> bool {
+        matches!(self, SetOp::Union | SetOp::SymmetricDifference)
+    }
+
+    /// Whether elements occurring in both slices are yielded.
+    #[inline]
+    fn keeps_both(self) -> bool {
+        matches!(self, SetOp::Union | SetOp::Intersection)
+    }
+}
+
+/// An iterator over the result of a set operation on two slices sorted by the comparator `F`,
+/// merging both in a single pass.
+pub struct SortedSetIter<'a, T, F = fn(&T, &T) -> Ordering> {
+    a: Peekable<Iter<'a, T>>,
+    b: Peekable<Iter<'a, T>>,
+    op: SetOp,
+    cmp: F,
+}
+
+impl<'a, T, F> SortedSetIter<'a, T, F> {
+    #[inline]
+    fn new(a: &'a [T], b: &'a [T], op: SetOp, cmp: F) -> Self {
+        Self {
+            a: a.iter().peekable(),
+            b: b.iter().peekable(),
+            op,
+            cmp,
+        }
+    }
+}
+
+impl<'a, T, F> Iterator for SortedSetIter<'a, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ord = match (self.a.peek(), self.b.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => (self.cmp)(a, b),
+            };
+
+            match ord {
+                Ordering::Less => {
+                    let a = self.a.next();
+                    if self.op.keeps_left() {
+                        return a;
+                    }
+                    // Nothing can match anymore.
+                    self.b.peek()?;
+                }
+                Ordering::Greater => {
+                    let b = self.b.next();
+                    if self.op.keeps_right() {
+                        return b;
+                    }
+                    // Nothing can match anymore.
+                    self.a.peek()?;
+                }
+                Ordering::Equal => {
+                    let a = self.a.next();
+                    self.b.next();
+                    if self.op.keeps_both() {
+                        return a;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn collect<'a>(iter: impl Iterator<Item = &'a i32>) -> Vec<i32> {
+        iter.copied().collect()
+    }
+
+    #[test_case(&[1, 2, 3], &[2, 3, 4], &[1, 2, 3, 4], &[2, 3], &[1], &[1, 4]; "overlapping")]
+    #[test_case(&[], &[1, 2], &[1, 2], &[], &[], &[1, 2]; "left empty")]
+    #[test_case(&[1, 2], &[], &[1, 2], &[], &[1, 2], &[1, 2]; "right empty")]
+    #[test_case(&[1, 2], &[3, 4], &[1, 2, 3, 4], &[], &[1, 2], &[1, 2, 3, 4]; "disjoint")]
+    fn set_ops(a: &[i32], b: &[i32], u: &[i32], i: &[i32], d: &[i32], sd: &[i32]) {
+        assert_eq!(collect(union(a, b)), u);
+        assert_eq!(collect(union_hash(a, b)), u);
+        assert_eq!(collect(union_sorted(a, b)), u);
+
+        assert_eq!(collect(intersection(a, b)), i);
+        assert_eq!(collect(intersection_hash(a, b)), i);
+        assert_eq!(collect(intersection_sorted(a, b)), i);
+
+        assert_eq!(collect(difference(a, b)), d);
+        assert_eq!(collect(difference_hash(a, b)), d);
+        assert_eq!(collect(difference_sorted(a, b)), d);
+
+        let mut sorted_sd = collect(symmetric_difference(a, b));
+        sorted_sd.sort_unstable();
+        assert_eq!(sorted_sd, sd);
+        let mut sorted_sd = collect(symmetric_difference_hash(a, b));
+        sorted_sd.sort_unstable();
+        assert_eq!(sorted_sd, sd);
+        assert_eq!(collect(symmetric_difference_sorted(a, b)), sd);
+
+        let disjoint = i.is_empty();
+        assert_eq!(is_disjoint(a, b), disjoint);
+        assert_eq!(is_disjoint_hash(a, b), disjoint);
+        assert_eq!(is_disjoint_sorted(a, b), disjoint);
+    }
+
+    #[test]
+    fn sorted_multiset() {
+        let a = [1, 1, 1, 2, 4];
+        let b = [1, 2, 2, 3];
+        assert_eq!(collect(union_sorted(&a, &b)), vec![1, 1, 1, 2, 2, 3, 4]);
+        assert_eq!(collect(intersection_sorted(&a, &b)), vec![1, 2]);
+        assert_eq!(collect(difference_sorted(&a, &b)), vec![1, 1, 4]);
+        assert_eq!(
+            collect(symmetric_difference_sorted(&a, &b)),
+            vec![1, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn unsorted_keeps_order() {
+        let a = [3, 1, 3, 2];
+        let b = [2, 5];
+        assert_eq!(collect(union(&a, &b)), vec![3, 1, 3, 2, 5]);
+        assert_eq!(collect(difference_hash(&a, &b)), vec![3, 1, 3]);
+        assert_eq!(collect(symmetric_difference(&a, &b)), vec![3, 1, 3, 5]);
+    }
+
+    #[test]
+    fn sorted_by() {
+        let a = [(1, 'a'), (3, 'a'), (4, 'a')];
+        let b = [(2, 'b'), (3, 'b')];
+        let keys = |iter: &mut dyn Iterator<Item = &(i32, char)>| iter.copied().collect::<Vec<_>>();
+        assert_eq!(
+            keys(&mut union_sorted_by_key(&a, &b, |i| i.0)),
+            vec![(1, 'a'), (2, 'b'), (3, 'a'), (4, 'a')]
+        );
+        assert_eq!(
+            keys(&mut intersection_sorted_by_key(&a, &b, |i| i.0)),
+            vec![(3, 'a')]
+        );
+        assert_eq!(
+            keys(&mut symmetric_difference_sorted_by_key(&a, &b, |i| i.0)),
+            vec![(1, 'a'), (2, 'b'), (4, 'a')]
+        );
+        assert!(!is_disjoint_sorted_by_key(&a, &b, |i| i.0));
+
+        let desc_a = [4, 3, 1];
+        let desc_b = [3, 2];
+        assert_eq!(
+            collect(difference_sorted_by(&desc_a, &desc_b, |a, b| b.cmp(a))),
+            vec![4, 1]
+        );
+        assert!(is_disjoint_sorted_by(&desc_a, &[2], |a, b| b.cmp(a)));
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Error returned if nested vectors don't have the required shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// A row has a different length than the first one.
+    Ragged {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// `len` items can't be split into rows of `width` items.
+    NotDivisible { len: usize, width: usize },
+    /// Rows of width zero were requested.
+    ZeroWidth,
+    /// The row offsets aren't ascending or don't cover all items.
+    InvalidOffsets,
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::Ragged { row, len, expected } => write!(
+                f,
+                "row {} has {} items but {} were expected",
+                row, len, expected
+            ),
+            ShapeError::NotDivisible { len, width } => {
+                write!(f, "{} items can't be split into rows of {}", len, width)
+            }
+            ShapeError::ZeroWidth => write!(f, "rows must not be empty"),
+            ShapeError::InvalidOffsets => write!(f, "row offsets don't match the items"),
+        }
+    }
+}
+
+impl Error for ShapeError {}
+
+/// How [`transpose`] handles rows of different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fill<T> {
+    /// Fail with [`ShapeError::Ragged`].
+    Strict,
+    /// Cut all rows to the length of the shortest one.
+    Truncate,
+    /// Pad all rows with the value to the length of the longest one.
+    Pad(T),
+    /// Leave missing items out, so columns built from short rows are shorter.
+    Skip,
+}
+
+/// Turns the rows of `rows` into columns, handling rows of different lengths according to `fill`.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{transpose, Fill};
+/// let rows = vec![vec![1, 2, 3], vec![4, 5]];
+/// assert_eq!(
+///     transpose(rows.clone(), Fill::Pad(0)).unwrap(),
+///     vec![vec![1, 4], vec![2, 5], vec![3, 0]]
+/// );
+/// assert_eq!(
+///     transpose(rows.clone(), Fill::Skip).unwrap(),
+///     vec![vec![1, 4], vec![2, 5], vec![3]]
+/// );
+/// assert_eq!(transpose(rows.clone(), Fill::Truncate).unwrap(), vec![vec![1, 4], vec![2, 5]]);
+/// assert!(transpose(rows, Fill::Strict).is_err());
+/// ```
+pub fn transpose<T: Clone>(rows: Vec<Vec<T>>, fill: Fill<T>) -> Result<Vec<Vec<T>>, ShapeError> {
+    let lens = rows.iter().map(Vec::len);
+    let width = match fill {
+        Fill::Truncate => lens.min().unwrap_or(0),
+        Fill::Strict => {
+            let expected = rows.first().map_or(0, Vec::len);
+            if let Some((row, len)) = lens.enumerate().find(|(_, len)| *len != expected) {
+                return Err(ShapeError::Ragged { row, len, expected });
+            }
+            expected
+        }
+        Fill::Pad(_) | Fill::Skip => lens.max().unwrap_or(0),
+    };
+
+    let mut iters = rows.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
+    let mut columns = Vec::with_capacity(width);
+    for _ in 0..width {
+        let mut column = Vec::with_capacity(iters.len());
+        for iter in iters.iter_mut() {
+            match (iter.next(), &fill) {
+                (Some(item), _) => column.push(item),
+                (None, Fill::Pad(value)) => column.push(value.clone()),
+                (None, _) => {}
+            }
+        }
+        columns.push(column);
+    }
+
+    Ok(columns)
+}
+
+/// Concatenates `rows` and returns the items together with the offsets of the rows. Row `i` is
+/// `items[offsets[i]..offsets[i + 1]]`, so there is one more offset than rows. Use [`unflatten`]
+/// to restore the rows.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{flatten, unflatten};
+/// let rows = vec![vec!['a', 'b'], vec![], vec!['c']];
+/// let (items, offsets) = flatten(rows.clone());
+/// assert_eq!(items, vec!['a', 'b', 'c']);
+/// assert_eq!(offsets, vec![0, 2, 2, 3]);
+/// assert_eq!(unflatten(items, &offsets).unwrap(), rows);
+/// ```
+pub fn flatten<T>(rows: Vec<Vec<T>>) -> (Vec<T>, Vec<usize>) {
+    let mut offsets = Vec::with_capacity(rows.len() + 1);
+    offsets.push(0);
+    let mut items = Vec::with_capacity(rows.iter().map(Vec::len).sum());
+    for row in rows {
+        items.extend(row);
+        offsets.push(items.len());
+    }
+    (items, offsets)
+}
+
+/// Splits `items` into rows at the `offsets` returned by [`flatten`].
+pub fn unflatten<T>(items: Vec<T>, offsets: &[usize]) -> Result<Vec<Vec<T>>, ShapeError> {
+    let valid = match (offsets.first(), offsets.last()) {
+        (Some(first), Some(last)) => {
+            *first == 0 && *last == items.len() && offsets.windows(2).all(|w| w[0] <= w[1])
+        }
+        _ => items.is_empty(),
+    };
+    if !valid {
+        return Err(ShapeError::InvalidOffsets);
+    }
+
+    let mut items = items.into_iter();
+    Ok(offsets
+        .windows(2)
+        .map(|w| items.by_ref().take(w[1] - w[0]).collect())
+        .collect())
+}
+
+/// Splits `items` into rows of `width` items. This is the inverse of flattening equally long rows
+/// and, unlike [`IterExt::chunked`](crate::itertools::IterExt::chunked), fails instead of
+/// returning a shorter last row.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::{reshape, ShapeError};
+/// assert_eq!(reshape(vec![1, 2, 3, 4, 5, 6], 3).unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// assert_eq!(
+///     reshape(vec![1, 2, 3], 2),
+///     Err(ShapeError::NotDivisible { len: 3, width: 2 })
+/// );
+/// ```
+pub fn reshape<T>(items: Vec<T>, width: usize) -> Result<Vec<Vec<T>>, ShapeError> {
+    if width == 0 {
+        return Err(ShapeError::ZeroWidth);
+    }
+    if !items.len().is_multiple_of(width) {
+        return Err(ShapeError::NotDivisible {
+            len: items.len(),
+            width,
+        });
+    }
+
+    let rows = items.len() / width;
+    let mut items = items.into_iter();
+    Ok((0..rows)
+        .map(|_| items.by_ref().take(width).collect())
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![], vec![]; "empty")]
+    #[test_case(vec![vec![1, 2, 3]], vec![vec![1], vec![2], vec![3]]; "single row")]
+    #[test_case(vec![vec![1, 2], vec![3, 4], vec![5, 6]], vec![vec![1, 3, 5], vec![2, 4, 6]]; "rect")]
+    fn transpose_rect(rows: Vec<Vec<i32>>, exp: Vec<Vec<i32>>) {
+        for fill in [Fill::Strict, Fill::Truncate, Fill::Pad(0), Fill::Skip].iter() {
+            let columns = transpose(rows.clone(), *fill).unwrap();
+            assert_eq!(columns, exp);
+            if !rows.is_empty() {
+                assert_eq!(transpose(columns, *fill).unwrap(), rows);
+            }
+        }
+    }
+
+    #[test]
+    fn transpose_ragged() {
+        let rows = vec![vec![1, 2], vec![3], vec![4, 5, 6]];
+        assert_eq!(
+            transpose(rows.clone(), Fill::Strict),
+            Err(ShapeError::Ragged {
+                row: 1,
+                len: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            transpose(rows.clone(), Fill::Truncate).unwrap(),
+            vec![vec![1, 3, 4]]
+        );
+        assert_eq!(
+            transpose(rows.clone(), Fill::Pad(0)).unwrap(),
+            vec![vec![1, 3, 4], vec![2, 0, 5], vec![0, 0, 6]]
+        );
+        assert_eq!(
+            transpose(rows, Fill::Skip).unwrap(),
+            vec![vec![1, 3, 4], vec![2, 5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn flatten_roundtrip() {
+        let rows = vec![vec![], vec![1], vec![2, 3, 4], vec![]];
+        let (items, offsets) = flatten(rows.clone());
+        assert_eq!(offsets, vec![0, 0, 1, 4, 4]);
+        assert_eq!(unflatten(items.clone(), &offsets).unwrap(), rows);
+
+        assert_eq!(flatten(Vec::<Vec<u8>>::new()), (vec![], vec![0]));
+        assert_eq!(unflatten(Vec::<u8>::new(), &[]), Ok(vec![]));
+    }
+
+    #[test_case(&[]; "missing")]
+    #[test_case(&[1, 4]; "not from zero")]
+    #[test_case(&[0, 3]; "too short")]
+    #[test_case(&[0, 5]; "too long")]
+    #[test_case(&[0, 3, 1, 4]; "descending")]
+    fn invalid_offsets(offsets: &[usize]) {
+        assert_eq!(
+            unflatten(vec![1, 2, 3, 4], offsets),
+            Err(ShapeError::InvalidOffsets)
+        );
+    }
+
+    #[test]
+    fn reshape_inverse() {
+        let items = (0..12).collect::<Vec<_>>();
+        for width in [1, 2, 3, 4, 6, 12].iter() {
+            let rows = reshape(items.clone(), *width).unwrap();
+            assert_eq!(rows.len(), 12 / width);
+            assert_eq!(flatten(rows).0, items);
+        }
+        assert_eq!(reshape(Vec::<u8>::new(), 3), Ok(vec![]));
+        assert_eq!(reshape(items, 0), Err(ShapeError::ZeroWidth));
+    }
+}
@@ -0,0 +1,465 @@
+use std::{
+    iter::FromIterator,
+    ops::{Bound, Deref, RangeBounds},
+};
+
+use super::{
+    difference_sorted, extend_sorted, extend_sorted_unique, intersection_sorted,
+    is_disjoint_sorted, is_subset, push_sorted_unique, symmetric_difference_sorted, union_sorted,
+    SortedSetIter,
+};
+
+/// A `Vec` which is always sorted in ascending order. Duplicates are allowed, see [`SortedSet`]
+/// for a variant which keeps every element only once.
+///
+/// Dereferences to a slice, so all read-only slice methods are available.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::SortedVec;
+/// let mut v: SortedVec<_> = vec![5, 1, 3].into();
+/// v.insert(2);
+/// v.extend(vec![4, 1]);
+/// assert_eq!(v.as_slice(), &[1, 1, 2, 3, 4, 5]);
+/// assert_eq!(v.range(2..4), &[2, 3]);
+/// assert_eq!(v.rank(&3), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T>(Vec<T>);
+
+impl<T: Ord> SortedVec<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Inserts `item` after all elements which are less than or equal to it and returns its
+    /// index.
+    #[inline]
+    pub fn insert(&mut self, item: T) -> usize {
+        let idx = self.0.partition_point(|i| *i <= item);
+        self.0.insert(idx, item);
+        idx
+    }
+
+    /// Removes one occurrence of `item`. Returns `true` if it was found.
+    #[inline]
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.0.binary_search(item) {
+            Ok(idx) => {
+                self.0.remove(idx);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Removes and returns the element at `index`. Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_index(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    /// Removes and returns the largest element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.0.binary_search(item).is_ok()
+    }
+
+    /// Returns the number of elements which are less than `item`.
+    #[inline]
+    pub fn rank(&self, item: &T) -> usize {
+        self.0.partition_point(|i| i < item)
+    }
+
+    /// Returns all elements within `range`.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let start = match range.start_bound() {
+            Bound::Included(s) => self.0.partition_point(|i| i < s),
+            Bound::Excluded(s) => self.0.partition_point(|i| i <= s),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(e) => self.0.partition_point(|i| i <= e),
+            Bound::Excluded(e) => self.0.partition_point(|i| i < e),
+            Bound::Unbounded => self.0.len(),
+        };
+        &self.0[start..end.max(start)]
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.0.retain(f)
+    }
+
+    /// Returns the sorted union of both vectors, see [`union_sorted`].
+    #[inline]
+    pub fn union<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        union_sorted(&self.0, &other.0)
+    }
+
+    /// See [`intersection_sorted`].
+    #[inline]
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        intersection_sorted(&self.0, &other.0)
+    }
+
+    /// See [`difference_sorted`].
+    #[inline]
+    pub fn difference<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        difference_sorted(&self.0, &other.0)
+    }
+
+    /// See [`symmetric_difference_sorted`].
+    #[inline]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        symmetric_difference_sorted(&self.0, &other.0)
+    }
+
+    /// Returns `true` if every element of `self` occurs at least as often in `other`. Runs in
+    /// O(n + m).
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        is_subset(&self.0, &other.0)
+    }
+
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        is_disjoint_sorted(&self.0, &other.0)
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for SortedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedVec<T> {
+    #[inline]
+    fn from(mut vec: Vec<T>) -> Self {
+        vec.sort();
+        Self(vec)
+    }
+}
+
+impl<T> From<SortedVec<T>> for Vec<T> {
+    #[inline]
+    fn from(vec: SortedVec<T>) -> Self {
+        vec.0
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    /// Sorts the new items and merges them in a single pass instead of inserting them one by one.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        extend_sorted(&mut self.0, iter);
+    }
+}
+
+impl<T> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// A sorted `Vec` which contains every element at most once.
+///
+/// Example:
+///
+/// ```rust
+/// use utilsrs::vectools::SortedSet;
+/// let mut a: SortedSet<_> = vec![3, 1, 3, 2].into_iter().collect();
+/// assert!(!a.insert(2));
+/// assert_eq!(a.as_slice(), &[1, 2, 3]);
+///
+/// let b: SortedSet<_> = vec![2, 3, 4].into_iter().collect();
+/// assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![2, 3]);
+/// assert!(!a.is_subset(&b));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedSet<T>(SortedVec<T>);
+
+impl<T: Ord> SortedSet<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self(SortedVec::new())
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(SortedVec::with_capacity(capacity))
+    }
+
+    /// Inserts `item` if it's not already in the set. Returns `true` if the value was inserted.
+    #[inline]
+    pub fn insert(&mut self, item: T) -> bool {
+        push_sorted_unique(&mut self.0 .0, item)
+    }
+
+    /// Removes `item`. Returns `true` if it was in the set.
+    #[inline]
+    pub fn remove(&mut self, item: &T) -> bool {
+        self.0.remove(item)
+    }
+
+    /// Removes and returns the element at `index`. Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_index(&mut self, index: usize) -> T {
+        self.0.remove_index(index)
+    }
+
+    /// Removes and returns the largest element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.0.contains(item)
+    }
+
+    /// Returns the number of elements which are less than `item`.
+    #[inline]
+    pub fn rank(&self, item: &T) -> usize {
+        self.0.rank(item)
+    }
+
+    /// Returns all elements within `range`.
+    #[inline]
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        self.0.range(range)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0.into_vec()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.0.retain(f)
+    }
+
+    #[inline]
+    pub fn union<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        self.0.union(&other.0)
+    }
+
+    #[inline]
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        self.0.intersection(&other.0)
+    }
+
+    #[inline]
+    pub fn difference<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        self.0.difference(&other.0)
+    }
+
+    #[inline]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SortedSetIter<'a, T> {
+        self.0.symmetric_difference(&other.0)
+    }
+
+    /// Returns `true` if every element of `self` is in `other`. Runs in O(n + m).
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.is_disjoint(&other.0)
+    }
+}
+
+impl<T: Ord> Default for SortedSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for SortedSet<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedSet<T> {
+    #[inline]
+    fn from(mut vec: Vec<T>) -> Self {
+        vec.sort();
+        vec.dedup();
+        Self(SortedVec(vec))
+    }
+}
+
+impl<T> From<SortedSet<T>> for Vec<T> {
+    #[inline]
+    fn from(set: SortedSet<T>) -> Self {
+        set.0 .0
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedSet<T> {
+    /// Sorts the new items and merges them in a single pass instead of inserting them one by one.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        extend_sorted_unique(&mut self.0 .0, iter);
+    }
+}
+
+impl<T> IntoIterator for SortedSet<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedSet<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorted_vec() {
+        let mut v: SortedVec<_> = vec![4, 2, 2, 9].into();
+        assert_eq!(v.insert(2), 2);
+        assert_eq!(v.insert(0), 0);
+        assert!(v.remove(&2));
+        assert!(!v.remove(&5));
+        assert_eq!(v.as_slice(), &[0, 2, 2, 4, 9]);
+
+        v.extend(vec![10, 3, 2]);
+        assert_eq!(v.as_slice(), &[0, 2, 2, 2, 3, 4, 9, 10]);
+        assert!(v.contains(&3));
+        assert_eq!(v.rank(&2), 1);
+        assert_eq!(v.rank(&11), 8);
+
+        assert_eq!(v.range(..), v.as_slice());
+        assert_eq!(v.range(2..=4), &[2, 2, 2, 3, 4]);
+        assert_eq!(
+            v.range((Bound::Excluded(2), Bound::Unbounded)),
+            &[3, 4, 9, 10]
+        );
+        assert!(v.range(5..5).is_empty());
+        assert!(v.range((Bound::Included(9), Bound::Excluded(3))).is_empty());
+    }
+
+    #[test]
+    fn sorted_set() {
+        let mut a: SortedSet<_> = vec![5, 1, 5, 3].into();
+        assert!(a.insert(4));
+        assert!(!a.insert(4));
+        a.extend(vec![1, 2, 6, 6]);
+        assert_eq!(a.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        let b: SortedSet<_> = (2..5).collect();
+        assert!(b.is_subset(&a));
+        assert!(a.is_superset(&b));
+        assert!(!a.is_subset(&b));
+        assert_eq!(
+            a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+            vec![1, 5, 6]
+        );
+        assert!(!a.is_disjoint(&b));
+        assert!(a.is_disjoint(&SortedSet::from(vec![0, 7])));
+    }
+}